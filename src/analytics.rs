@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use web_sys::{js_sys, wasm_bindgen::{JsCast as _, JsValue}};
+
+use crate::data::AnalyticsConfig;
+use crate::elements::{json_escape, window};
+
+/// Receives events (e.g. link clicks) reported by the widgets through [`track`].
+pub trait AnalyticsSink: Send + Sync {
+    /// Records a single event.
+    /// - `name`: The kind of event, e.g. `link_click`.
+    /// - `props`: Details of the event as key/value pairs, e.g. `("url", link)`.
+    fn event(&self, name: &str, props: &[(&str, &str)]);
+}
+
+/// Drops every event, used unless `data.toml` configures analytics.
+pub struct NoopAnalytics;
+
+impl AnalyticsSink for NoopAnalytics {
+    fn event(&self, _name: &str, _props: &[(&str, &str)]) {}
+}
+
+/// Posts each event as JSON (`{"event":"link_click","props":{"url":"…"}}`) to an endpoint, ignoring the response.
+pub struct EndpointAnalytics {
+    endpoint: String,
+}
+
+impl EndpointAnalytics {
+    pub fn new(endpoint: String) -> Self {
+        Self { endpoint }
+    }
+
+    /// The JSON body posted for an event, see [`AnalyticsSink::event`] for the parameters.
+    fn payload(name: &str, props: &[(&str, &str)]) -> String {
+        let props = props.iter()
+            .map(|(key, value)| format!(r#""{}":"{}""#, json_escape(key), json_escape(value)))
+            .collect::<Vec<_>>()
+            .join(",");
+        format!(r#"{{"event":"{}","props":{{{}}}}}"#, json_escape(name), props)
+    }
+}
+
+impl AnalyticsSink for EndpointAnalytics {
+    fn event(&self, name: &str, props: &[(&str, &str)]) {
+        let body = Self::payload(name, props);
+        let mut request = ehttp::Request::post(&self.endpoint, body.into_bytes());
+        request.headers = ehttp::Headers::new(&[("Content-Type", "application/json")]);
+        ehttp::fetch(request, |result| {
+            if let Err(error) = result {
+                log::debug!("Failed to send analytics event: {error}");
+            }
+        });
+    }
+}
+
+/// Forwards events to the page's global `gtag` function (Google Analytics), when the page loaded it.
+pub struct GtagAnalytics;
+
+impl AnalyticsSink for GtagAnalytics {
+    fn event(&self, name: &str, props: &[(&str, &str)]) {
+        let Some(window) = window() else {
+            return;
+        };
+        let gtag = js_sys::Reflect::get(&window, &JsValue::from_str("gtag")).ok()
+            .and_then(|gtag| gtag.dyn_into::<js_sys::Function>().ok());
+        let Some(gtag) = gtag else {
+            log::debug!("No gtag function on the page, dropping event {name}");
+            return;
+        };
+        let params = js_sys::Object::new();
+        for (key, value) in props {
+            if let Err(error) = js_sys::Reflect::set(&params, &JsValue::from_str(key), &JsValue::from_str(value)) {
+                log::debug!("Failed to set the gtag parameter {key}: {error:?}");
+            }
+        }
+        if let Err(error) = gtag.call3(&JsValue::NULL, &JsValue::from_str("event"), &JsValue::from_str(name), &params) {
+            log::debug!("Failed to send gtag event {name}: {error:?}");
+        }
+    }
+}
+
+/// Builds the sink configured in `data.toml`, or [`NoopAnalytics`] when none is.
+pub fn sink_from_config(config: Option<&AnalyticsConfig>) -> Arc<dyn AnalyticsSink> {
+    match config {
+        Some(AnalyticsConfig::Endpoint { url }) => Arc::new(EndpointAnalytics::new(url.to_owned())),
+        Some(AnalyticsConfig::Gtag) => Arc::new(GtagAnalytics),
+        None => Arc::new(NoopAnalytics),
+    }
+}
+
+fn sink_id() -> egui::Id {
+    egui::Id::new("analytics_sink")
+}
+
+/// Installs the sink that [`track`] reports to from now on.
+pub fn set_sink(ctx: &egui::Context, sink: Arc<dyn AnalyticsSink>) {
+    ctx.data_mut(|data| data.insert_temp(sink_id(), sink));
+}
+
+/// Reports an event to the installed sink, dropping it when none is installed.
+/// - `ctx`: The egui context the sink was installed on.
+/// - `name`: The kind of event, e.g. `link_click`.
+/// - `props`: Details of the event as key/value pairs.
+pub fn track(ctx: &egui::Context, name: &str, props: &[(&str, &str)]) {
+    if let Some(sink) = ctx.data(|data| data.get_temp::<Arc<dyn AnalyticsSink>>(sink_id())) {
+        sink.event(name, props);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoint_payload_is_escaped_json() {
+        assert_eq!(EndpointAnalytics::payload("page_view", &[]), r#"{"event":"page_view","props":{}}"#);
+        assert_eq!(
+            EndpointAnalytics::payload("link_click", &[("url", "https://example.com/?q=\"rust\""), ("path", "C:\\maps")]),
+            r#"{"event":"link_click","props":{"url":"https://example.com/?q=\"rust\"","path":"C:\\maps"}}"#,
+        );
+        assert_eq!(
+            EndpointAnalytics::payload("note\n", &[("text", "line one\nline two\t\u{1}")]),
+            r#"{"event":"note\n","props":{"text":"line one\nline two\t\u0001"}}"#,
+        );
+    }
+}
@@ -1,10 +1,10 @@
-use std::{collections::HashMap, f32::consts::PI, vec};
+use std::{collections::HashMap, f32::consts::PI, sync::{atomic::{AtomicBool, Ordering}, Arc}, vec};
 
-use egui::{include_image, panel::TopBottomSide, pos2, vec2, Align, AtomExt, Color32, Frame, Id, ImageSource, Label, Margin, Mesh, Rect, Scene, Sense, Stroke, Style, TextWrapMode, Theme, UiBuilder};
+use egui::{include_image, panel::TopBottomSide, vec2, Align, Color32, Frame, Id, ImageSource, Label, Margin, Rect, Scene, Sense, Stroke, Style, Theme, UiBuilder, Vec2};
 use serde::de;
-use web_sys::window;
+use web_sys::wasm_bindgen::{closure::Closure, JsCast as _};
 
-use crate::{data::{Data, ProjectHighlight, Skill}, elements::{add_highlighted_project, paint_angular_gradient, skill_frameplate, socials, ButtonWithUnderline}};
+use crate::{analytics::{set_sink, sink_from_config}, clock::{animate_bool, animate_value, now}, data::{resolve_url, Data, PageSection, Palette}, route::Route, elements::{window, current_hash, current_url_without_hash, share_button, set_location_hash, collapsible_text, skill_cloud, scroll_progress, UnderlineFit, contact_form, download_file, hero, labeled_separator, logo, paint_logo, paint_angular_fade, paint_angular_gradient_deg, paint_conic_gradient, register_section, scroll_to_section, poll_preloads, preload, section_heading, set_link_target, set_min_touch_target, set_underline_easing, set_thumbnail_border, table_of_contents, FormState, ProjectGallery, Toasts, reveal_on_scroll, skill_frameplate_sized, scrollbar_style, socials, status_pill, timeline, SkillChipStyle, ButtonWithUnderline}};
 
 #[derive(serde::Deserialize, serde::Serialize)]
 #[serde(default)] // if we add new fields, give them default values when deserializing old state
@@ -19,8 +19,52 @@ pub struct TemplateApp {
     animations: HashMap<Id, (AnimateDirection, f32)>, // Map of animations by their ID, as well as their direction and progress
     #[serde(skip)]
     data: Data, // Data struct to hold skills and other data
+    #[serde(skip)]
+    project_query: Debounced<String>, // Search box for the project highlights, settled before filtering
+    project_gallery: ProjectGallery, // Active search and tag filters, restored on reload
+    #[serde(skip)]
+    route: Route, // The page the location hash last pointed at, see [`TemplateApp::sync_route`]
+    #[serde(skip)]
+    contact_form: FormState, // Contact form fields and submission status
+    #[serde(skip)]
+    pub max_content_width: f32, // Widest the page's column gets, centered with equal margins on wider windows
+    #[serde(skip)]
+    pub reduced_motion: bool, // Set from the browser's `prefers-reduced-motion`, snaps animations to their end state
+    #[serde(skip)]
+    toasts: Toasts, // Transient notifications, shown above everything else
+    #[serde(skip)]
+    load_state: LoadState, // Whether the loading splash is still covering the page
+    #[serde(skip)]
+    print_mode: bool, // Light theme, no top panel, every project on one page and no hover effects
+    #[serde(skip)]
+    print: PrintState, // Pending Print button requests and the browser's print events, see [`TemplateApp::sync_print_mode`]
+    theme_choice: ThemeChoice, // Picked in the top bar; `System` follows the OS color scheme
+    #[serde(skip)]
+    theme_transition: ThemeTransition,
+    #[serde(skip)]
+    high_contrast_installed: bool, // Whether the dark theme's slot holds the high-contrast style, see [`TemplateApp::sync_theme`]
+    #[serde(skip)]
+    screen_size: Option<Debounced<ScreenSize>>, // Layout tier, only switched once a resize across a breakpoint settles
+    #[serde(skip)]
+    debug_overlay: bool, // Debug builds only: labels the scene and clip rects and enables egui's debug-on-hover
+    #[cfg(debug_assertions)]
+    #[serde(skip)]
+    data_reload: DataReload,
 }
 
+/// Smallest width and height of buttons and links on the Small tier, the recommended size of a touch target.
+const MIN_TOUCH_TARGET: f32 = 44.0;
+
+/// How long the window width has to stay in a new [`ScreenSize`] tier before the layout switches to it.
+const RESIZE_SETTLE_MS: u64 = 150;
+
+/// Toggles between the light and dark theme, pinning whichever is not showing as the [`ThemeChoice`].
+const THEME_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut::new(egui::Modifiers::SHIFT, egui::Key::D);
+
+/// Toggles [`TemplateApp::debug_overlay`], only in debug builds.
+#[cfg(debug_assertions)]
+const DEBUG_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut::new(egui::Modifiers::SHIFT, egui::Key::F12);
+
 impl Default for TemplateApp {
     fn default() -> Self {
         Self {
@@ -29,6 +73,23 @@ impl Default for TemplateApp {
             root_url: get_base_url(),
             animations: HashMap::new(),
             data: crate::data::Data::new(),
+            project_query: Debounced::new(String::new(), 250),
+            project_gallery: ProjectGallery::default(),
+            route: Route::default(),
+            contact_form: FormState::default(),
+            max_content_width: 1100.0,
+            reduced_motion: prefers_reduced_motion(),
+            toasts: Toasts::default(),
+            load_state: LoadState::Initializing,
+            print_mode: false,
+            print: PrintState::default(),
+            theme_choice: ThemeChoice::default(),
+            high_contrast_installed: false,
+            theme_transition: ThemeTransition::default(),
+            screen_size: None,
+            debug_overlay: false,
+            #[cfg(debug_assertions)]
+            data_reload: DataReload::default(),
         }
     }
 }
@@ -38,21 +99,621 @@ impl TemplateApp {
     pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
         // This is also where you can customize the look and feel of egui using
         // `cc.egui_ctx.set_visuals` and `cc.egui_ctx.set_fonts`.
-        let dark_style = Self::get_dark_theme_style(&cc.egui_ctx);
-        cc.egui_ctx.set_style_of(Theme::Dark, dark_style);
-        let light_style = Self::get_light_theme_style(&cc.egui_ctx);
-        cc.egui_ctx.set_style_of(Theme::Light, light_style);
 
         // Load previous app state (if any).
         // Note that you must enable the `persistence` feature for this to work.
-        if let Some(storage) = cc.storage {
+        let mut app: Self = if let Some(storage) = cc.storage {
             eframe::get_value(storage, eframe::APP_KEY).unwrap_or_default()
         } else {
             Default::default()
+        };
+        let dark_style = Self::get_dark_theme_style(&cc.egui_ctx, &app.data.palette.dark);
+        cc.egui_ctx.set_style_of(Theme::Dark, dark_style);
+        let light_style = Self::get_light_theme_style(&cc.egui_ctx, &app.data.palette.light);
+        cc.egui_ctx.set_style_of(Theme::Light, light_style);
+        // Seed the search box with the restored query
+        app.project_query = Debounced::new(app.project_gallery.query.clone(), 250);
+        app.reduced_motion = prefers_reduced_motion();
+        if app.reduced_motion {
+            // Widgets and helpers reading `animation_time` from the style snap instantly
+            cc.egui_ctx.all_styles_mut(|style| style.animation_time = 0.0);
+        }
+        // Link and project clicks go to the sink `data.toml` configures, a no-op otherwise
+        set_sink(&cc.egui_ctx, sink_from_config(app.data.analytics.as_ref()));
+        // Fetch the above-the-fold images now, the splash waits on them
+        let mut primary_assets = vec![resolve_url(&app.root_url, "/assets/pride-flag.gif")];
+        if let ImageSource::Uri(logo_uri) = app.data.logo_source(&app.root_url) {
+            primary_assets.push(logo_uri.into_owned());
+        }
+        preload(&cc.egui_ctx, &primary_assets.iter().map(String::as_str).collect::<Vec<_>>());
+        app
+    }
+
+    /// The layout tier for the given window width, switching only once a resize across a breakpoint has settled.
+    /// The pass that switches tiers is discarded and rerun, so the jump between layouts (and zoom factors) never
+    /// shows a frame laid out for the old tier.
+    /// - `ctx`: The egui context.
+    /// - `screen_width`: The window width, see [`ScreenSize::width`].
+    /// - `breakpoints`: Where each tier starts.
+    fn settled_screen_size(&mut self, ctx: &egui::Context, screen_width: f32, breakpoints: &Breakpoints) -> ScreenSize {
+        let measured = ScreenSize::from_width(screen_width, breakpoints);
+        // The first frame has nothing to settle from
+        let tier = self.screen_size.get_or_insert_with(|| Debounced::new(measured, RESIZE_SETTLE_MS));
+        if *tier.pending_mut() != measured {
+            *tier.pending_mut() = measured;
+            tier.mark_changed(ctx);
+        }
+        if tier.tick(ctx) {
+            ctx.request_discard("screen size tier changed");
+        }
+        *tier.value()
+    }
+
+    /// Adds the footer, pinned to the bottom of the viewport when the content above is short and flowing right
+    /// after it otherwise. The footer's height is measured on the previous pass, which is rerun when it changes.
+    /// Returns whether the Retry button of the debug-only [`TemplateApp::data_source_note`] was clicked.
+    /// - `ui`: The scroll area's content ui, with the page laid out above the cursor.
+    fn footer(&self, ui: &mut egui::Ui) -> bool {
+        let height_id = Id::new("footer_height");
+        let footer_height = ui.data(|data| data.get_temp::<f32>(height_id)).unwrap_or(0.0);
+        // Measured within the content, so scrolling doesn't change it
+        let used_height = ui.cursor().top() - ui.min_rect().top();
+        let spacer = ui.clip_rect().height() - used_height - footer_height - ui.spacing().item_spacing.y;
+        ui.add_space(spacer.max(0.0));
+
+        let footer = ui.vertical(|ui| {
+            egui::warn_if_debug_build(ui);
+            #[cfg(debug_assertions)]
+            let retry_clicked = self.data_source_note(ui);
+            #[cfg(not(debug_assertions))]
+            let retry_clicked = false;
+            if let Some(updated) = &self.data.updated {
+                let when = updated.relative().unwrap_or_else(|| updated.to_string());
+                ui.label(egui::RichText::new(format!("Updated {when}")).small().weak()).on_hover_text(updated.to_string());
+            }
+            powered_by_egui_and_eframe(ui);
+            retry_clicked
+        });
+        let measured = footer.response.rect.height();
+        if measured != footer_height {
+            ui.data_mut(|data| data.insert_temp(height_id, measured));
+            ui.ctx().request_discard("footer height changed");
         }
+        footer.inner
+    }
+
+    /// Adds the hero banner with the name and tagline, and the status pill when `data.toml` sets one, returning their rect.
+    /// - `ui`: The page column.
+    /// - `screen_size`: The layout tier, scaling the banner and its text.
+    fn hero_section(&self, ui: &mut egui::Ui, screen_size: ScreenSize) -> Rect {
+        let palette = self.palette(ui.ctx());
+        let hero_height = 100.0 + 40.0 * screen_size.as_f32();
+        let rect = hero(ui, "ZeroUni", "Fullstack developer / backend enthusiast", (palette.accent, palette.secondary_bg, PI / 2.0), hero_height, get_font_size(&screen_size, 4) * 2.0).rect;
+        let Some(status) = self.data.status.as_deref().filter(|status| !status.trim().is_empty()) else {
+            return rect;
+        };
+        ui.add_space(6.0);
+        let pill = ui.vertical_centered(|ui| status_pill(ui, status, palette.accent, get_font_size(&screen_size, 0))).inner;
+        rect.union(pill.rect)
     }
 
-    pub fn get_dark_theme_style(ctx: &egui::Context) -> Style {
+    /// Adds the pride flag beside the socials, bio and source links, returning the block's rect.
+    /// - `ui`: The page column.
+    /// - `screen_size`: The layout tier.
+    /// - `with_skills`: Whether to list the skills in the same column, below the bio.
+    fn about_section(&self, ui: &mut egui::Ui, screen_size: ScreenSize, with_skills: bool) -> Rect {
+        let main_info = Frame::group(ui.style()).stroke(Stroke::NONE);
+        main_info.show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.add(egui::Image::new(resolve_url(&self.root_url, "/assets/pride-flag.gif")).fit_to_original_size(0.3));
+                ui.add_space(16.0);
+                ui.vertical(|ui| {
+                    let mut opener = egui::Frame::group(ui.style()).stroke(Stroke::NONE).fill(Color32::TRANSPARENT).inner_margin(Margin::same(4)).outer_margin(Margin::same(0)).corner_radius(2).begin(ui);
+                    {
+                        // The name and tagline live in the hero banner above
+                        opener.content_ui.horizontal(|ui| {
+                            ui.vertical(|ui| {
+                                socials(ui, "github/@ZeroUni", "https://github.com/ZeroUni", &None, get_font_size(&screen_size, 1));
+                                socials(ui, "linkedin/@ZeroUni", "https://www.linkedin.com/in/ZeroUni", &None, get_font_size(&screen_size, 1));
+                            });
+                        });
+                    }
+                    let opening_rect = opener.allocate_space(ui).rect;
+                    // Paint a transparent gray gradient before painting the contents
+                    paint_angular_fade(ui.painter(), opening_rect.expand2(vec2(8.0, 0.0)), self.palette(ui.ctx()).border.gamma_multiply(0.2), 1., vec2(2.0, 0.8));
+                    opener.paint(ui);
+
+                    if !self.data.about.trim().is_empty() {
+                        // Keep the bio scannable on phones, where it would otherwise fill the first screen
+                        let preview_chars = if screen_size == ScreenSize::Small { 160 } else { 400 };
+                        collapsible_text(ui, "about", &self.data.about, preview_chars, get_font_size(&screen_size, 0));
+                    }
+
+                    if with_skills {
+                        self.skills_list(ui, screen_size, opening_rect.width());
+                    }
+
+                    // Both links keep the theme's link color
+                    ui.horizontal(|ui| {
+                        ui.add(egui::github_link_file!(
+                            "https://github.com/ZeroUni/portfolio/blob/main/",
+                            "Source Code"
+                        ).open_in_new_tab(true));
+                        ui.add(egui::github_link_file!(
+                            "https://github.com/emilk/eframe_template/blob/main/",
+                            "[egui]"
+                        ).open_in_new_tab(true));
+                    });
+                });
+            });
+        }).response.rect
+    }
+
+    /// Adds the skills under a labeled divider, as a row of chips or as a word cloud once any skill has a level.
+    /// - `ui`: The UI to add the skills to.
+    /// - `screen_size`: The layout tier, sizing the chips' text.
+    /// - `max_width`: The width the chips wrap at.
+    fn skills_list(&self, ui: &mut egui::Ui, screen_size: ScreenSize, max_width: f32) {
+        ui.scope(|ui| {
+            ui.set_max_width(max_width);
+            labeled_separator(ui, "Skills");
+        });
+        // Once any skill has a level, size them all by proficiency as a word cloud
+        let skills = self.data.listed_skills();
+        if skills.iter().any(|skill| skill.level.is_some()) {
+            ui.scope(|ui| {
+                ui.set_max_width(max_width);
+                skill_cloud(ui, &skills, &self.root_url);
+            });
+        } else {
+            ui.horizontal_wrapped(|ui| {
+                ui.set_max_width(max_width);
+                // Fit the taller of the label and a 16px icon, so every chip lines up
+                let chip_style = SkillChipStyle { height: Some(get_font_size(&screen_size, 0).max(16.0) + 12.0), ..Default::default() };
+                for skill in &skills {
+                    skill_frameplate_sized(ui, &skill.name, skill.color(), skill.text_color(), &skill.icon_uri(&self.root_url), get_font_size(&screen_size, 0), &chip_style);
+                }
+                if skills.is_empty() {
+                    let placeholder = match &self.data.load_error {
+                        Some(error) => egui::RichText::new(format!("Couldn't load skills: {error}")).small().weak(),
+                        None => egui::RichText::new("No skills to display").italics().weak(),
+                    };
+                    ui.label(placeholder);
+                }
+            });
+        }
+    }
+
+    /// Adds the project highlights with their search box and gallery, returning the rect reserved for them.
+    /// - `ui`: The page column.
+    /// - `ctx`: The egui context.
+    /// - `screen_size`: The layout tier.
+    /// - `beside`: The bio block, which the projects sit to the right of on [`ScreenSize::Large`].
+    /// - `below`: The section above, which the projects otherwise follow.
+    fn projects_section(&mut self, ui: &mut egui::Ui, ctx: &egui::Context, screen_size: ScreenSize, beside: Option<Rect>, below: Option<Rect>) -> Rect {
+        let top_left = below.map_or(ui.cursor().min, |rect| rect.left_bottom() + vec2(0.0, 16.0));
+        let (highlight_space, highlight_layout) = match (screen_size, beside) {
+            (ScreenSize::Large, Some(beside)) => (ui.allocate_rect(Rect::from_min_size(beside.right_top() + vec2(8.0, 0.0), vec2(ui.max_rect().width() - beside.width() - 8.0, 200.0)), Sense::hover()),
+            egui::Layout::top_down(egui::Align::Max)),
+            _ => (ui.allocate_rect(Rect::from_min_size(top_left, vec2(ui.available_width(), 200.0)), Sense::click()),
+            egui::Layout::top_down(egui::Align::LEFT)),
+        };
+
+        ui.scope_builder(egui::UiBuilder::default().max_rect(highlight_space.rect).sense(Sense::click()).layout(highlight_layout), |ui| {
+            let outer_frame = egui::Frame::group(ui.style()).fill(ui.visuals().widgets.noninteractive.weak_bg_fill.gamma_multiply_u8(127).blend(ui.visuals().extreme_bg_color.gamma_multiply_u8(100))).outer_margin(egui::Margin::symmetric(8, 0));
+            reveal_on_scroll(ui, Id::new("highlights_section"), |ui| outer_frame.show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    // Capped by what the frame's margins and stroke leave, so narrow windows don't overflow
+                    ui.set_max_width(ui.available_width().min(800.0));
+                    ui.with_layout(egui::Layout::left_to_right(egui::Align::TOP), |ui| {
+                        ui.add_space(12.0);
+                        section_heading(ui, Section::Highlights.title(), 2)
+                            .on_hover_text(Section::Highlights.shortcut_hint(ctx));
+                        ui.add_space(12.0);
+                        let search_response = ui.add(egui::TextEdit::singleline(self.project_query.pending_mut()).hint_text("Search projects").desired_width(160.0));
+                        if search_response.changed() {
+                            self.project_query.mark_changed(ctx);
+                        }
+                    });
+                });
+                if self.project_query.tick(ctx) {
+                    self.project_gallery.set_query(self.project_query.value().clone());
+                }
+                let root_url = self.root_url.to_owned();
+                self.project_gallery.max_columns = match screen_size {
+                    ScreenSize::Small => 1,
+                    ScreenSize::Medium | ScreenSize::Large => 2,
+                };
+                self.project_gallery.screen_size = screen_size;
+                ui.set_max_width(ui.available_width().min(1100.0));
+                self.project_gallery.show(ui, ctx, &root_url, self.data.project_highlights_mut());
+                // "Clear filters" empties the query behind the search box's back
+                if self.project_gallery.query != *self.project_query.value() {
+                    self.project_query = Debounced::new(self.project_gallery.query.clone(), 250);
+                }
+            }));
+        }).response.rect
+    }
+
+    /// Adds the experience timeline, returning its rect.
+    /// - `ui`: The page column.
+    /// - `screen_size`: The layout tier, picking between alternating and stacked entries.
+    fn timeline_section(&self, ui: &mut egui::Ui, screen_size: ScreenSize) -> Rect {
+        let width = ui.available_width().min(800.0);
+        reveal_on_scroll(ui, Id::new("timeline_section"), |ui| {
+            egui::Frame::new().outer_margin(egui::Margin::symmetric(8, 4)).show(ui, |ui| {
+                ui.set_max_width(width - 16.0);
+                section_heading(ui, "Experience", 2);
+                ui.add_space(4.0);
+                timeline(ui, &self.data.timeline, screen_size);
+            })
+        }).response.rect
+    }
+
+    /// Adds the contact details and, when an endpoint is configured, the contact form, returning their rect.
+    /// - `ui`: The page column.
+    /// - `ctx`: The egui context.
+    /// - `width`: The widest the section's contents get.
+    fn contact_section(&mut self, ui: &mut egui::Ui, ctx: &egui::Context, width: f32) -> Rect {
+        let contact_frame = egui::Frame::group(ui.style())
+            .fill(ui.visuals().widgets.noninteractive.weak_bg_fill.gamma_multiply_u8(127).blend(ui.visuals().extreme_bg_color.gamma_multiply_u8(100)))
+            .outer_margin(egui::Margin::symmetric(8, 4));
+
+        reveal_on_scroll(ui, Id::new("contact_section"), |ui| contact_frame.show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.set_max_width(width);
+                ui.with_layout(egui::Layout::left_to_right(egui::Align::TOP), |ui| {
+                    section_heading(ui, Section::Contact.title(), 2)
+                        .on_hover_text(Section::Contact.shortcut_hint(ctx));
+                });
+                ui.hyperlink_to("[email]", "mailto:zd.muhs@gmail.com");
+                if ui.add(ButtonWithUnderline::new("[copy]").frame(false)).on_hover_text("Copy email address").clicked() {
+                    ctx.copy_text("zd.muhs@gmail.com".to_owned());
+                    self.toasts.push("Copied to clipboard", 2.0);
+                }
+            });
+
+            if let Some(endpoint) = &self.data.contact_endpoint {
+                ui.set_max_width(width);
+                contact_form(ui, &mut self.contact_form, endpoint, &mut self.toasts);
+            }
+        })).response.rect
+    }
+
+    /// Returns the given animation duration, or zero when reduced motion is requested.
+    /// - `seconds`: The duration of the animation in seconds.
+    pub fn animation_time(&self, seconds: f32) -> f32 {
+        if self.reduced_motion {
+            0.0
+        } else {
+            seconds
+        }
+    }
+
+    /// Shows a full-screen splash with the logo and a spinner until the primary assets have loaded, then fades it out.
+    /// - `ctx`: The egui context to draw on.
+    fn show_loading_splash(&mut self, ctx: &egui::Context) {
+        const SPLASH_TIMEOUT: f64 = 5.0; // Never keep visitors waiting on a slow or missing asset
+
+        if self.load_state == LoadState::Initializing {
+            // `poll_preloads` keeps repainting while the assets from `new` load, even when nothing on screen animates
+            let loaded = !poll_preloads(ctx);
+            if loaded || now(ctx) > SPLASH_TIMEOUT {
+                self.load_state = LoadState::Ready;
+            }
+        }
+
+        let opacity = animate_bool(ctx, Id::new("loading_splash"), self.load_state == LoadState::Initializing, self.animation_time(0.3));
+        if opacity <= 0.0 {
+            return;
+        }
+
+        let palette = self.palette(ctx);
+        egui::Area::new(Id::new("loading_splash"))
+            .order(egui::Order::Foreground)
+            .fixed_pos(ctx.screen_rect().min)
+            .show(ctx, |ui| {
+                ui.multiply_opacity(opacity);
+                let screen_rect = ctx.screen_rect();
+                // Swallow clicks meant for the page underneath
+                ui.allocate_rect(screen_rect, Sense::click_and_drag());
+                // A faint accent sweep around the logo, fading back into the page color at the top
+                let panel_fill = ui.visuals().panel_fill;
+                let tint = panel_fill.lerp_to_gamma(palette.accent, 0.15);
+                paint_conic_gradient(ui.painter(), screen_rect, screen_rect.center(), &[(0.0, panel_fill), (0.5, tint)]);
+
+                let content_rect = Rect::from_center_size(screen_rect.center(), vec2(96.0, 136.0));
+                ui.scope_builder(UiBuilder::new().max_rect(content_rect).layout(egui::Layout::top_down(Align::Center)), |ui| {
+                    let (logo_rect, _) = ui.allocate_exact_size(vec2(96.0, 96.0), Sense::hover());
+                    paint_logo(ui, self.data.logo_source(&self.root_url), logo_rect);
+                    ui.add_space(12.0);
+                    if self.reduced_motion {
+                        ui.label("Loading…");
+                    } else {
+                        ui.add(egui::Spinner::new().size(24.0));
+                    }
+                });
+            });
+    }
+
+    /// Handles the keyboard shortcuts: [`THEME_SHORTCUT`] toggles the theme and the number keys jump to a [`Section`].
+    /// Nothing fires while a text field (e.g. the project search) has keyboard focus.
+    /// - `ctx`: The egui context to read input from.
+    fn handle_shortcuts(&mut self, ctx: &egui::Context) {
+        if ctx.wants_keyboard_input() {
+            return;
+        }
+        if ctx.input_mut(|i| i.consume_shortcut(&THEME_SHORTCUT)) {
+            self.toggle_theme(ctx);
+        }
+        #[cfg(debug_assertions)]
+        if ctx.input_mut(|i| i.consume_shortcut(&DEBUG_SHORTCUT)) {
+            self.toggle_debug_overlay(ctx);
+        }
+        for section in Section::ALL {
+            let shortcut = egui::KeyboardShortcut::new(egui::Modifiers::NONE, section.key());
+            if ctx.input_mut(|i| i.consume_shortcut(&shortcut)) {
+                self.navigate(ctx, section.route());
+            }
+        }
+    }
+
+    /// Follows changes to the location hash made outside the app (a deep link, the Back button or an edited url),
+    /// scrolling to the section of the new [`Route`]. Project routes are left to [`ProjectGallery::show`]'s overlay.
+    /// - `ctx`: The egui context.
+    fn sync_route(&mut self, ctx: &egui::Context) {
+        let Some(hash) = current_hash() else {
+            return;
+        };
+        let route = Route::from_hash(&hash);
+        if route == self.route {
+            return;
+        }
+        let previous = std::mem::replace(&mut self.route, route);
+        // Closing a project's overlay returns to the page as it was, rather than jumping to a section
+        if matches!(previous, Route::Project(_)) {
+            return;
+        }
+        if let Some(section) = Section::for_route(&self.route) {
+            scroll_to_section(ctx, section.title());
+        }
+    }
+
+    /// Goes to the given route, adding it to the browser history so Back returns here, and scrolls to its section.
+    /// - `ctx`: The egui context.
+    /// - `route`: Where to go.
+    fn navigate(&mut self, ctx: &egui::Context, route: Route) {
+        if route != self.route {
+            set_location_hash(&route.to_hash());
+        }
+        if let Some(section) = Section::for_route(&route) {
+            scroll_to_section(ctx, section.title());
+        }
+        self.route = route;
+    }
+
+    /// Debug builds only: fetches `data.toml` from the dev server whenever the window regains focus and swaps it in,
+    /// so edits show up without a rebuild. Release builds only ever use the copy baked in with `include_str!`.
+    /// - `ctx`: The egui context, repainted once the fetched data arrives.
+    #[cfg(debug_assertions)]
+    fn reload_data_on_focus(&mut self, ctx: &egui::Context) {
+        let focused = ctx.input(|i| i.focused);
+        if focused && !self.data_reload.focused {
+            self.fetch_data(ctx);
+        }
+        self.data_reload.focused = focused;
+
+        // A panicking fetch callback can't leave the slot half-written, so a poisoned lock is safe to use
+        match self.data_reload.fetched.lock().unwrap_or_else(std::sync::PoisonError::into_inner).take() {
+            Some(Ok(data)) => {
+                log::info!("Reloaded {} skills and {} projects from data.toml", data.skills.len(), data.project_highlights.len());
+                set_sink(ctx, sink_from_config(data.analytics.as_ref()));
+                self.data = data;
+                self.data_reload.source = DataSource::Remote;
+                self.data_reload.error = None;
+            }
+            Some(Err(error)) => self.data_reload.error = Some(error),
+            None => {}
+        }
+    }
+
+    /// Debug builds only: starts fetching `data.toml` from the dev server, see [`TemplateApp::reload_data_on_focus`].
+    /// - `ctx`: The egui context, repainted once the fetch finishes.
+    #[cfg(debug_assertions)]
+    fn fetch_data(&self, ctx: &egui::Context) {
+        let url = format!("{}/data.toml", self.root_url.trim_end_matches('/'));
+        let fetched = self.data_reload.fetched.clone();
+        let ctx = ctx.clone();
+        ehttp::fetch(ehttp::Request::get(&url), move |result| {
+            let data = result
+                .and_then(|response| response.text().map(str::to_owned).ok_or_else(|| "response is not UTF-8".to_owned()))
+                .and_then(|text| Data::from_toml(&text).map_err(|e| e.to_string()));
+            if let Err(e) = &data {
+                log::warn!("Failed to reload {url}: {e}");
+            }
+            *fetched.lock().unwrap_or_else(std::sync::PoisonError::into_inner) = Some(data);
+            ctx.request_repaint();
+        });
+    }
+
+    /// Debug builds only: notes in the footer when the page still shows the bundled data because fetching
+    /// `data.toml` failed, with a button to try again. Returns whether that button was clicked.
+    /// - `ui`: The footer's ui.
+    #[cfg(debug_assertions)]
+    fn data_source_note(&self, ui: &mut egui::Ui) -> bool {
+        let Some(error) = self.data_reload.error.as_ref().filter(|_| self.data_reload.source == DataSource::Embedded) else {
+            return false;
+        };
+        ui.horizontal_wrapped(|ui| {
+            ui.label(egui::RichText::new("Showing the bundled data, fetching data.toml failed").small().weak())
+                .on_hover_text(error);
+            let retry = egui::RichText::new("Retry").small();
+            ui.add(ButtonWithUnderline::new(retry).frame(false)).clicked()
+        }).inner
+    }
+
+    /// The title and url the Share button offers: the open project's, or the page's on any other route.
+    fn share_target(&self) -> (String, String) {
+        let page_url = current_url_without_hash().unwrap_or_else(|| self.root_url.clone());
+        let url = match self.route {
+            Route::Home => page_url,
+            _ => format!("{page_url}{}", self.route.to_hash()),
+        };
+        let title = match &self.route {
+            Route::Project(slug) => self.data.project_highlights().iter()
+                .find(|project| &project.slug == slug)
+                .map_or_else(|| "ZeroUni - Portfolio".to_owned(), |project| format!("{} - ZeroUni", project.title)),
+            _ => "ZeroUni - Portfolio".to_owned(),
+        };
+        (title, url)
+    }
+
+    /// Applies [`TemplateApp::theme_choice`], re-deriving the theme every frame when it is [`ThemeChoice::System`]
+    /// so an OS switch shows up right away. Print mode keeps its light theme.
+    /// egui only keeps a light and a dark style, so the high-contrast style is swapped into the dark one's place.
+    /// - `ctx`: The egui context whose theme is set.
+    fn sync_theme(&mut self, ctx: &egui::Context) {
+        if self.print_mode {
+            return;
+        }
+        let high_contrast = self.high_contrast();
+        if high_contrast != self.high_contrast_installed {
+            let style = if high_contrast {
+                Self::get_high_contrast_style(ctx)
+            } else {
+                Self::get_dark_theme_style(ctx, &self.data.palette.dark)
+            };
+            // A fade into the dark theme would otherwise end on the style just replaced
+            if let Some((Theme::Dark, target)) = &mut self.theme_transition.target {
+                *target = style.clone();
+            }
+            ctx.set_style_of(Theme::Dark, style);
+            self.high_contrast_installed = high_contrast;
+        }
+        let theme = match self.theme_choice {
+            ThemeChoice::Light => Theme::Light,
+            ThemeChoice::Dark | ThemeChoice::HighContrast => Theme::Dark,
+            // The browser repaints when the color scheme changes, so reading it here is enough to follow it
+            ThemeChoice::System => prefers_color_scheme().or_else(|| ctx.system_theme()).unwrap_or(Theme::Dark),
+        };
+        if ctx.options(|options| options.theme_preference) != theme.into() {
+            ctx.set_theme(theme);
+        }
+    }
+
+    /// Whether the high-contrast theme is showing. Print mode keeps its light theme regardless.
+    fn high_contrast(&self) -> bool {
+        self.theme_choice == ThemeChoice::HighContrast && !self.print_mode
+    }
+
+    /// The colors of the theme on screen: the high-contrast palette while it shows, otherwise the palette from `data.toml`.
+    /// - `ctx`: The egui context, whose current theme picks the palette.
+    fn palette(&self, ctx: &egui::Context) -> Palette {
+        if self.high_contrast() {
+            Palette::high_contrast()
+        } else {
+            *self.data.palette.for_theme(ctx.theme())
+        }
+    }
+
+    /// Switches between the light and dark theme, see [`THEME_SHORTCUT`].
+    /// - `ctx`: The egui context whose current theme is flipped.
+    fn toggle_theme(&mut self, ctx: &egui::Context) {
+        self.theme_choice = match ctx.theme() {
+            Theme::Light => ThemeChoice::Dark,
+            Theme::Dark => ThemeChoice::Light,
+        };
+    }
+
+    /// Crossfades the colors of the previous theme into the new one after a theme switch.
+    /// The target theme's style is blended each frame and restored exactly once the fade ends.
+    /// Print mode and reduced motion switch instantly.
+    /// - `ctx`: The egui context whose theme is followed.
+    fn crossfade_theme(&mut self, ctx: &egui::Context) {
+        const ANIMATION_ID: &str = "theme_crossfade";
+        let theme = ctx.theme();
+        let previous_theme = self.theme_transition.shown_theme.replace(theme);
+
+        if let Some(previous_theme) = previous_theme.filter(|previous| *previous != theme) {
+            // What is on screen right now, possibly halfway through an earlier fade
+            let from = ctx.style_of(previous_theme).visuals.clone();
+            if let Some((faded_theme, style)) = self.theme_transition.target.take() {
+                ctx.set_style_of(faded_theme, style);
+            }
+            if !self.reduced_motion && !self.print_mode {
+                self.theme_transition.from = Some(from);
+                self.theme_transition.target = Some((theme, (*ctx.style_of(theme)).clone()));
+                // Restart the fade from the old colors
+                animate_value(ctx, Id::new(ANIMATION_ID), 0.0, 0.0);
+            }
+        }
+
+        let (Some((target_theme, target)), Some(from)) = (&self.theme_transition.target, &self.theme_transition.from) else {
+            return;
+        };
+        let t = animate_value(ctx, Id::new(ANIMATION_ID), 1.0, self.animation_time(0.2));
+        if t >= 1.0 {
+            ctx.set_style_of(*target_theme, target.clone());
+            self.theme_transition.target = None;
+            self.theme_transition.from = None;
+        } else {
+            let mut style = target.clone();
+            style.visuals = blend_visuals(from, &target.visuals, t);
+            ctx.set_style_of(*target_theme, style);
+        }
+    }
+
+    /// Shows or hides the debug overlay, see [`TemplateApp::debug_overlay`].
+    /// Debug builds only, like `egui::Context::set_debug_on_hover`.
+    #[cfg(debug_assertions)]
+    fn toggle_debug_overlay(&mut self, ctx: &egui::Context) {
+        self.debug_overlay = !self.debug_overlay;
+        ctx.set_debug_on_hover(self.debug_overlay);
+    }
+
+    /// Enters or leaves print mode to follow the Print button and the browser's print events.
+    /// Called at the start of each frame.
+    /// - `ctx`: The egui context, whose theme is switched to light while printing.
+    fn sync_print_mode(&mut self, ctx: &egui::Context) {
+        if !self.print.listening {
+            self.print.listening = true;
+            listen_for_print_events(ctx, &self.print.browser_printing);
+        }
+
+        let print_mode = self.print.print_at.is_some() || self.print.browser_printing.load(Ordering::Relaxed);
+        if print_mode == self.print_mode {
+            return;
+        }
+        self.print_mode = print_mode;
+        self.project_gallery.print_layout = print_mode;
+        if print_mode {
+            self.print.theme_before_print = Some(ctx.options(|options| options.theme_preference));
+            ctx.set_theme(Theme::Light);
+        } else if let Some(theme_preference) = self.print.theme_before_print.take() {
+            ctx.set_theme(theme_preference);
+        }
+    }
+
+    /// Opens the browser's print dialog once the print layout has been on screen for a moment.
+    /// `window.print()` blocks until the dialog closes, so print mode ends right after.
+    /// - `ctx`: The egui context.
+    fn print_when_ready(&mut self, ctx: &egui::Context) {
+        let Some(print_at) = self.print.print_at else {
+            return;
+        };
+        let now = now(ctx);
+        if now < print_at {
+            ctx.request_repaint_after(std::time::Duration::from_secs_f64(print_at - now));
+            return;
+        }
+        self.print.print_at = None;
+        if let Some(Err(error)) = window().map(|window| window.print()) {
+            log::warn!("Failed to open the print dialog: {error:?}");
+        }
+        ctx.request_repaint();
+    }
+
+    pub fn get_dark_theme_style(ctx: &egui::Context, palette: &Palette) -> Style {
         use egui::{
             style::{Selection, Visuals, Widgets},
             Color32, FontFamily, FontId, CornerRadius, Stroke, TextStyle,
@@ -71,59 +732,59 @@ impl TemplateApp {
         .into();
     
         // Primary background color
-        let primary_bg_color = Color32::from_rgb(16, 17, 18);
+        let primary_bg_color = palette.primary_bg;
     
         // Configure visuals
         style.visuals = Visuals::dark();
         style.visuals.extreme_bg_color = primary_bg_color;
-        style.visuals.override_text_color = Some(Color32::from_rgb(240, 235, 216));
+        style.visuals.override_text_color = Some(palette.text);
         style.visuals.widgets = Widgets {
             noninteractive: egui::style::WidgetVisuals {
                 bg_fill: primary_bg_color,
-                bg_stroke: Stroke::new(1.0, Color32::from_gray(60)),
-                fg_stroke: Stroke::new(1.0, Color32::from_rgb(240, 235, 216)),
+                bg_stroke: Stroke::new(1.0, palette.border),
+                fg_stroke: Stroke::new(1.0, palette.text),
                 corner_radius: CornerRadius::same(4),
-                weak_bg_fill: Color32::from_gray(32),
+                weak_bg_fill: palette.surface,
                 expansion: 0.0,
             },
             inactive: egui::style::WidgetVisuals {
-                bg_fill: Color32::from_rgb(19, 41, 61),
-                bg_stroke: Stroke::new(1.0, Color32::from_rgb(22, 50, 79)),
-                fg_stroke: Stroke::new(1.0, Color32::from_rgb(240, 235, 216)),
+                bg_fill: palette.secondary_bg,
+                bg_stroke: Stroke::new(1.0, palette.border),
+                fg_stroke: Stroke::new(1.0, palette.text),
                 corner_radius: CornerRadius::same(4),
-                weak_bg_fill: Color32::from_gray(32),
+                weak_bg_fill: palette.surface,
                 expansion: 0.0,
             },
             hovered: egui::style::WidgetVisuals {
-                bg_fill: Color32::from_rgb(50, 50, 50),
-                bg_stroke: Stroke::new(1.0, Color32::WHITE),
-                fg_stroke: Stroke::new(1.0, Color32::WHITE),
+                bg_fill: palette.selection,
+                bg_stroke: Stroke::new(1.0, palette.text),
+                fg_stroke: Stroke::new(1.0, palette.text),
                 corner_radius: CornerRadius::same(4),
-                weak_bg_fill: Color32::from_gray(32),
+                weak_bg_fill: palette.surface,
                 expansion: 0.5,
             },
             active: egui::style::WidgetVisuals {
-                bg_fill: Color32::from_rgb(60, 60, 60),
-                bg_stroke: Stroke::new(1.0, Color32::WHITE),
-                fg_stroke: Stroke::new(1.0, Color32::from_rgb(240, 235, 216)),
+                bg_fill: palette.selection,
+                bg_stroke: Stroke::new(1.0, palette.text),
+                fg_stroke: Stroke::new(1.0, palette.text),
                 corner_radius: CornerRadius::same(4),
-                weak_bg_fill: Color32::from_gray(32),
+                weak_bg_fill: palette.surface,
                 expansion: 2.0,
             },
             open: egui::style::WidgetVisuals {
-                bg_fill: Color32::from_rgb(40, 40, 40),
-                bg_stroke: Stroke::new(1.0, Color32::WHITE),
-                fg_stroke: Stroke::new(1.0, Color32::from_rgb(240, 235, 216)),
+                bg_fill: palette.surface,
+                bg_stroke: Stroke::new(1.0, palette.text),
+                fg_stroke: Stroke::new(1.0, palette.text),
                 corner_radius: CornerRadius::same(4),
-                weak_bg_fill: Color32::from_gray(32),
+                weak_bg_fill: palette.surface,
                 expansion: 0.0,
             },
         };
     
         // Selection colors
         style.visuals.selection = Selection {
-            bg_fill: Color32::from_rgb(75, 75, 75),
-            stroke: Stroke::new(1.0, Color32::WHITE),
+            bg_fill: palette.selection,
+            stroke: Stroke::new(1.0, palette.text),
         };
     
         // Window settings
@@ -134,19 +795,52 @@ impl TemplateApp {
             spread: 0,
             color: Color32::from_black_alpha(128),
         };
-        style.visuals.window_fill = primary_bg_color;
-        style.visuals.window_stroke = Stroke::new(1.0, Color32::from_gray(60));
+        style.visuals.window_fill = palette.window_bg;
+        style.visuals.window_stroke = Stroke::new(1.0, palette.border);
+        style.visuals.hyperlink_color = palette.link;
         style.visuals.panel_fill = primary_bg_color;
     
         // Spacing settings
         //style.spacing.item_spacing = egui::vec2(8.0, 6.0);
         style.spacing.window_margin = egui::Margin::same(4);
         style.spacing.button_padding = egui::vec2(2.0, 2.0);
-    
+
+        style.spacing.scroll = Self::scroll_style();
+
+        style
+    }
+
+    /// The dark theme in pure black and white, with thick strokes and a saturated selection, for visitors who need
+    /// maximum contrast. Hovered and pressed widgets turn yellow, which also colors focus rings.
+    /// - `ctx`: The egui context whose current style is the starting point.
+    pub fn get_high_contrast_style(ctx: &egui::Context) -> Style {
+        use egui::{style::WidgetVisuals, CornerRadius, Stroke};
+
+        let palette = Palette::high_contrast();
+        let mut style = Self::get_dark_theme_style(ctx, &palette);
+        let highlight = palette.link;
+        let widget = |bg_stroke: Stroke, fg_stroke: Stroke, expansion: f32| WidgetVisuals {
+            bg_fill: palette.primary_bg,
+            weak_bg_fill: palette.surface,
+            bg_stroke,
+            fg_stroke,
+            corner_radius: CornerRadius::same(4),
+            expansion,
+        };
+        style.visuals.widgets.noninteractive = widget(Stroke::new(2.0, palette.border), Stroke::new(2.0, palette.text), 0.0);
+        style.visuals.widgets.inactive = widget(Stroke::new(2.0, palette.border), Stroke::new(2.0, palette.text), 0.0);
+        style.visuals.widgets.hovered = widget(Stroke::new(3.0, highlight), Stroke::new(3.0, highlight), 0.5);
+        style.visuals.widgets.active = widget(Stroke::new(3.0, highlight), Stroke::new(3.0, highlight), 1.0);
+        style.visuals.widgets.open = widget(Stroke::new(2.0, highlight), Stroke::new(2.0, palette.text), 0.0);
+        style.visuals.selection.stroke = Stroke::new(2.0, palette.text);
+        style.visuals.window_stroke = Stroke::new(2.0, palette.border);
+        // Shadows only blur the edges the strokes draw
+        style.visuals.window_shadow = egui::Shadow::NONE;
+
         style
     }
 
-    pub fn get_light_theme_style(ctx: &egui::Context) -> Style {
+    pub fn get_light_theme_style(ctx: &egui::Context, palette: &Palette) -> Style {
         use egui::{
             style::{Selection, Visuals, Widgets},
             Color32, FontFamily, FontId, CornerRadius, Stroke, TextStyle,
@@ -165,60 +859,60 @@ impl TemplateApp {
         .into();
     
         // Primary background color
-        let primary_bg_color = Color32::from_rgb(202, 233, 255);
-        let secondary_bg_color = Color32::from_rgb(27, 73, 101);
+        let primary_bg_color = palette.primary_bg;
+        let secondary_bg_color = palette.secondary_bg;
     
         // Configure visuals
         style.visuals = Visuals::light();
         style.visuals.extreme_bg_color = primary_bg_color;
-        style.visuals.override_text_color = Some(Color32::from_rgb(33, 34, 39));
+        style.visuals.override_text_color = Some(palette.text);
         style.visuals.widgets = Widgets {
             noninteractive: egui::style::WidgetVisuals {
                 bg_fill: secondary_bg_color,
-                bg_stroke: Stroke::new(1.0, Color32::from_rgb(99, 112, 116)),
-                fg_stroke: Stroke::new(1.0, Color32::from_rgb(95, 168, 211)),
+                bg_stroke: Stroke::new(1.0, palette.border),
+                fg_stroke: Stroke::new(1.0, palette.text),
                 corner_radius: CornerRadius::same(4),
-                weak_bg_fill: Color32::from_gray(32),
+                weak_bg_fill: palette.surface,
                 expansion: 0.0,
             },
             inactive: egui::style::WidgetVisuals {
                 bg_fill: secondary_bg_color,
-                bg_stroke: Stroke::new(1.0, Color32::from_rgb(99, 112, 116)),
-                fg_stroke: Stroke::new(1.0, Color32::from_rgb(33, 34, 39)),
+                bg_stroke: Stroke::new(1.0, palette.border),
+                fg_stroke: Stroke::new(1.0, palette.text),
                 corner_radius: CornerRadius::same(4),
-                weak_bg_fill: Color32::from_rgb(190, 233, 232),
+                weak_bg_fill: palette.surface,
                 expansion: 0.0,
             },
             hovered: egui::style::WidgetVisuals {
-                bg_fill: Color32::from_rgb(170, 185, 207),
-                bg_stroke: Stroke::new(1.0, Color32::from_rgb(99, 112, 116)),
-                fg_stroke: Stroke::new(1.0, Color32::from_rgb(33, 34, 39)),
+                bg_fill: palette.window_bg,
+                bg_stroke: Stroke::new(1.0, palette.border),
+                fg_stroke: Stroke::new(1.0, palette.text),
                 corner_radius: CornerRadius::same(4),
-                weak_bg_fill: Color32::from_rgb(139, 166, 169),
+                weak_bg_fill: palette.window_bg,
                 expansion: 0.5,
             },
             active: egui::style::WidgetVisuals {
-                bg_fill: Color32::from_rgb(60, 60, 60),
-                bg_stroke: Stroke::new(1.0, Color32::from_rgb(99, 112, 116)),
-                fg_stroke: Stroke::new(1.0, Color32::from_rgb(33, 34, 39)),
+                bg_fill: palette.selection,
+                bg_stroke: Stroke::new(1.0, palette.border),
+                fg_stroke: Stroke::new(1.0, palette.text),
                 corner_radius: CornerRadius::same(4),
-                weak_bg_fill: Color32::from_rgb(139, 166, 169),
+                weak_bg_fill: palette.window_bg,
                 expansion: 2.0,
             },
             open: egui::style::WidgetVisuals {
-                bg_fill: Color32::from_rgb(40, 40, 40),
-                bg_stroke: Stroke::new(1.0, Color32::from_rgb(99, 112, 116)),
-                fg_stroke: Stroke::new(1.0, Color32::from_rgb(33, 34, 39)),
+                bg_fill: palette.selection,
+                bg_stroke: Stroke::new(1.0, palette.border),
+                fg_stroke: Stroke::new(1.0, palette.text),
                 corner_radius: CornerRadius::same(4),
-                weak_bg_fill: Color32::from_gray(32),
+                weak_bg_fill: palette.surface,
                 expansion: 0.0,
             },
         };
     
         // Selection colors
         style.visuals.selection = Selection {
-            bg_fill: Color32::from_rgb(99, 112, 116),
-            stroke: Stroke::new(1.0, Color32::from_rgb(255, 255, 255)),
+            bg_fill: palette.selection,
+            stroke: Stroke::new(1.0, palette.primary_bg),
         };
     
         // Window settings
@@ -229,17 +923,30 @@ impl TemplateApp {
             spread: 0,
             color: Color32::from_black_alpha(128),
         };
-        style.visuals.window_fill = Color32::from_rgb(122, 156, 198);
-        style.visuals.window_stroke = Stroke::new(1.0, Color32::from_gray(60));
+        style.visuals.window_fill = palette.window_bg;
+        style.visuals.window_stroke = Stroke::new(1.0, palette.border);
+        style.visuals.hyperlink_color = palette.link;
         style.visuals.panel_fill = primary_bg_color;
     
         // Spacing settings
         //style.spacing.item_spacing = egui::vec2(8.0, 6.0);
         style.spacing.window_margin = egui::Margin::same(4);
         style.spacing.button_padding = egui::vec2(2.0, 2.0);
-    
+
+        style.spacing.scroll = Self::scroll_style();
+
         style
     }
+
+    /// Scroll bars shared by every theme, floating over the content. The thumb takes the widgets' text color and the
+    /// track the extreme background color, which the themes set to the panel fill.
+    fn scroll_style() -> egui::style::ScrollStyle {
+        egui::style::ScrollStyle {
+            bar_width: 8.0,
+            floating_width: 3.0,
+            ..egui::style::ScrollStyle::floating()
+        }
+    }
 }
 
 impl eframe::App for TemplateApp {
@@ -252,23 +959,30 @@ impl eframe::App for TemplateApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         // Put your widgets into a `SidePanel`, `TopBottomPanel`, `CentralPanel`, `Window` or `Area`.
         // For inspiration and more examples, go to https://emilk.github.io/egui
-        let screen_width = ctx.screen_rect().width() * ctx.zoom_factor();
-        let screen_size: ScreenSize = if screen_width < 768.0 {
-            ScreenSize::Small
-        } else if screen_width < 1028.0 {
-            ScreenSize::Medium
-        } else {
-            ScreenSize::Large
-        };
+        self.sync_print_mode(ctx);
+        self.sync_route(ctx);
+        self.sync_theme(ctx);
+        set_link_target(ctx, self.data.link_target);
+        set_underline_easing(ctx, self.data.underline_easing);
+        set_thumbnail_border(ctx, self.data.thumbnail_border.then(|| Stroke::new(1.0, self.palette(ctx).border)));
+        #[cfg(debug_assertions)]
+        self.reload_data_on_focus(ctx);
+        self.handle_shortcuts(ctx);
+        self.crossfade_theme(ctx);
+        let breakpoints = self.data.breakpoints;
+        breakpoints.install(ctx);
+        let screen_width = ScreenSize::width(ctx);
+        let screen_size = self.settled_screen_size(ctx, screen_width, &breakpoints);
+        // Phones get buttons and links big enough to tap, desktop keeps the compact layout
+        set_min_touch_target(ctx, if screen_size == ScreenSize::Small { Vec2::splat(MIN_TOUCH_TARGET) } else { Vec2::ZERO });
 
         log::debug!("Screen size: {:?}, zoom factor: {:?}, Screen Width: {:?}", screen_size, ctx.zoom_factor(), screen_width);
 
-        if screen_size.to_u8() == 1 && ctx.zoom_factor().ne(&(screen_width / 768.0)) {
-            // Normalize screen to 768 px
-            ctx.set_zoom_factor(screen_width / 768.0);
+        // Normalize small screens to the width of the Medium breakpoint
+        let zoom_factor = if screen_size == ScreenSize::Small { screen_width / breakpoints.medium } else { 1.0 };
+        if ctx.zoom_factor() != zoom_factor {
+            ctx.set_zoom_factor(zoom_factor);
             ctx.request_repaint();
-        } else if screen_size.to_u8() != 1 {
-            ctx.set_zoom_factor(1.0);
         }
 
         let panel_location = match screen_size {
@@ -277,12 +991,6 @@ impl eframe::App for TemplateApp {
             ScreenSize::Large => TopBottomSide::Top,
         };
 
-        let theme_preference: egui::Theme = ctx.theme();
-        let theme_text = match theme_preference {
-            egui::Theme::Light => "🌖",
-            egui::Theme::Dark => "🌞",
-        };
-        
         let menu_frame = egui::Frame {
             inner_margin: egui::Margin {
                 left: 12,
@@ -295,23 +1003,25 @@ impl eframe::App for TemplateApp {
             fill: ctx.style().visuals.window_fill,
             ..Default::default()
         };
-        egui::TopBottomPanel::new(panel_location, "top_panel").frame(menu_frame).show(ctx, |ui| {
+        egui::TopBottomPanel::new(panel_location, "top_panel").frame(menu_frame).show_animated(ctx, !self.print_mode, |ui| {
             egui::MenuBar::new().ui(ui, |ui| {
                 ui.add_space(8.0);
-                ui.add(
-                    egui::Image::new(ImageSource::Uri(format!("{}/assets/croissant.png", &self.root_url).into())).maintain_aspect_ratio(false)
-                    .fit_to_exact_size(vec2(48.0, 48.0)).corner_radius(32.0)
-                );
+                // Fit within 48×48 without stretching non-square logos
+                if logo(ui, self.data.logo_source(&self.root_url), vec2(48.0, 48.0), "Back to top").clicked() {
+                    self.project_gallery.close_project();
+                    self.navigate(ctx, Route::Home);
+                }
                 ui.add_space(20.0);
 
+                let portfolio_animation_time = self.animation_time(0.2);
                 let animation_value = 1.0 - self.animations.entry(Id::new("portfolio_button"))
                     .or_insert({
-                        ctx.animate_value_with_time(Id::new("portfolio_button"), 0.0, 0.2); // Tell the ctx to initialize the animation with a current value of 0.0
+                        animate_value(ctx, Id::new("portfolio_button"), 0.0, portfolio_animation_time); // Tell the ctx to initialize the animation with a current value of 0.0
                         (AnimateDirection::In, 0.0)
                     }).1;
                 let portfolio_text = egui::RichText::new("Portfolio")
                     .font(egui::FontId::new(20.0 * (1.1 - (0.1 * animation_value)), egui::FontFamily::Proportional))
-                    .color(ctx.style().visuals.override_text_color.unwrap_or(egui::Color32::WHITE));
+                    .color(ctx.style().visuals.text_color());
                 let test_button = ui.add(ButtonWithUnderline::new(portfolio_text).frame(false).inset([8.0 * animation_value, 8.0 * animation_value]));
                 if test_button.clicked() {
                     log::info!("Portfolio button clicked");
@@ -321,43 +1031,85 @@ impl eframe::App for TemplateApp {
                     // Only handle fade-in
                     if direction == &AnimateDirection::Out || *progress < 1.0 {
                         *direction = AnimateDirection::In;
-                        *progress = ctx.animate_value_with_time(Id::new("portfolio_button"), 1.0, 0.2);
+                        *progress = animate_value(ctx, Id::new("portfolio_button"), 1.0, portfolio_animation_time);
                     }
                 } else {
                     // Handle fade-out if not hovered
                     let (direction, progress) = self.animations.get_mut(&Id::new("portfolio_button")).unwrap();
                     if *direction == AnimateDirection::In || *progress > 0.0 {
                         *direction = AnimateDirection::Out;
-                        *progress = ctx.animate_value_with_time(Id::new("portfolio_button"), 0.0, 0.2);
+                        *progress = animate_value(ctx, Id::new("portfolio_button"), 0.0, portfolio_animation_time);
                     }
                 }
                 ui.add_space(8.0);
+                if screen_size != ScreenSize::Small {
+                    table_of_contents(ui, 20.0);
+                }
                 
                 #[cfg(debug_assertions)]
                 {
-                    let debug_button = ui.add(ButtonWithUnderline::new(egui::RichText::new("Debug").font(egui::FontId::new(20.0, egui::FontFamily::Proportional))).frame(false).inset([8.0, 8.0]));
+                    let debug_text = egui::RichText::new("Debug").font(egui::FontId::new(20.0, egui::FontFamily::Proportional));
+                    let debug_button = ui.add(ButtonWithUnderline::selectable(self.debug_overlay, debug_text).frame(false).inset([8.0, 8.0]))
+                        .on_hover_text(ctx.format_shortcut(&DEBUG_SHORTCUT));
                     if debug_button.clicked() {
-                        ctx.set_debug_on_hover(!ctx.debug_on_hover());
+                        self.toggle_debug_overlay(ctx);
                     }
                 }
                 
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                     ui.add_space(10.0);
-                    ui.style_mut().override_font_id = Some(egui::FontId::new(32.0, egui::FontFamily::Proportional));
-                    if ui.button(theme_text).clicked() {
-                        ctx.set_theme(if theme_preference == egui::Theme::Light {
-                            egui::Theme::Dark
-                        } else {
-                            egui::Theme::Light
-                        });
+                    // The high-contrast accent is black, so its underlines keep the text color instead
+                    let accent = (!self.high_contrast()).then(|| self.palette(ctx).accent);
+                    // The icons are drawn white, so tinting follows the theme's text
+                    let icon_tint = ui.visuals().text_color();
+                    let theme_shortcut = ctx.format_shortcut(&THEME_SHORTCUT);
+                    // Right to left, so the group reads System, Light, Dark, High contrast
+                    for choice in ThemeChoice::ALL.into_iter().rev() {
+                        let mut theme_button = ButtonWithUnderline::icon_button(egui::Image::new(choice.icon()).tint(icon_tint))
+                            .selected(self.theme_choice == choice).accessible_label(choice.label())
+                            .frame(false).underline_on_hover(true).underline_fit(UnderlineFit::Content);
+                        if let Some(accent) = accent {
+                            theme_button = theme_button.underline_color(accent);
+                        }
+                        if ui.add(theme_button).on_hover_text(format!("{}\n{theme_shortcut} toggles light and dark", choice.label())).clicked() {
+                            self.theme_choice = choice;
+                        }
+                    }
+
+                    ui.add_space(12.0);
+                    let print_text = egui::RichText::new("Print").font(egui::FontId::new(20.0, egui::FontFamily::Proportional));
+                    if ui.add(ButtonWithUnderline::new(print_text).frame(false).inset([8.0, 8.0])).clicked() {
+                        // Give the print layout (and the panel collapsing) time to settle before the dialog snapshots the page
+                        self.print.print_at = Some(now(ctx) + 0.3);
+                    }
+
+                    ui.add_space(12.0);
+                    let (share_title, share_url) = self.share_target();
+                    share_button(ui, &share_title, &share_url, 20.0, &mut self.toasts);
+
+                    if let Some(resume_url) = self.data.resume_url(&self.root_url) {
+                        ui.add_space(12.0);
+                        let resume_text = egui::RichText::new("Download Résumé").font(egui::FontId::new(20.0, egui::FontFamily::Proportional));
+                        let resume_button = ui.add(ButtonWithUnderline::new(resume_text).frame(false).inset([8.0, 8.0]))
+                            .on_hover_text(&resume_url);
+                        if resume_button.clicked() {
+                            let file_name = resume_url.rsplit('/').next().filter(|name| !name.is_empty()).unwrap_or("resume.pdf");
+                            download_file(ui.ctx(), &resume_url, file_name);
+                        }
                     }
                 });
             });
         });
 
         egui::CentralPanel::default().show(ctx, |ui| {
+            if self.print_mode {
+                // Nothing on paper reacts to the pointer
+                let widgets = &mut ui.style_mut().visuals.widgets;
+                widgets.hovered = widgets.inactive;
+                widgets.active = widgets.inactive;
+            }
             let bg_painter = ctx.layer_painter(egui::LayerId::background());
-            paint_angular_gradient(&bg_painter, ui.clip_rect(), egui::Color32::from_rgb(95, 15, 64), ui.visuals().extreme_bg_color, -PI / 4.0, vec2(0.4, 2.0));
+            paint_angular_gradient_deg(&bg_painter, ui.clip_rect(), self.palette(ctx).accent, ui.visuals().extreme_bg_color, -45.0, vec2(0.4, 2.0));
             egui::Frame::group(ui.style())
                 .inner_margin(egui::Margin::symmetric(18, 14))
                 .outer_margin(0.0)
@@ -368,119 +1120,93 @@ impl eframe::App for TemplateApp {
                         .zoom_range(1.0..=5.0);
 
                     let scene_rect_snapshot = self.scene_rect.clone();
-                    let scroll_area = egui::ScrollArea::both().max_width(ui.available_width()).min_scrolled_height(ui.available_height()).auto_shrink([false, false]).scroll([false, true]);
+                    let scroll_area = egui::ScrollArea::both().id_salt("page").max_width(ui.available_width()).min_scrolled_height(ui.available_height()).auto_shrink([false, false]).scroll([false, true]);
+                    // Only the page's bar auto-hides, scroll areas on the page keep the theme's
+                    let page_scroll = scrollbar_style(ctx, ui.make_persistent_id("page"), ui.spacing().scroll, self.data.scrollbar);
+                    let nested_scroll = std::mem::replace(&mut ui.spacing_mut().scroll, page_scroll);
 
-
-                    let scroll_response = scroll_area.show(ui, |ui| {
+                    let scroll_output = scroll_area.show(ui, |outer| {
+                        outer.spacing_mut().scroll = nested_scroll;
                         // The central panel the region left after adding TopPanel's and SidePanel's
-                        ui.set_min_height(ui.available_height());
-                        ui.set_width(ui.available_rect_before_wrap().width());
-                        let size_horizontal = ui.clip_rect().width();
-
-                        let main_info = Frame::group(ui.style()).stroke(Stroke::NONE);
-                        let main_space = main_info.show(ui, |ui| {
-                            ui.horizontal(|ui| {
-                                ui.add(egui::Image::new(self.root_url.to_owned() + "/assets/pride-flag.gif").fit_to_original_size(0.3));
-                                ui.add_space(16.0);
-                                ui.vertical(|ui| {
-                                    let mut opener = egui::Frame::group(ui.style()).stroke(Stroke::NONE).fill(Color32::TRANSPARENT).inner_margin(Margin::same(4)).outer_margin(Margin::same(0)).corner_radius(2).begin(ui);
-                                    {
-                                        opener.content_ui.horizontal(|ui| {
-                                            ui.vertical(|ui| {
-                                                ui.add_space(16.0);
-                                                ui.label(
-                                                    egui::RichText::new("ZeroUni").font(egui::FontId::new(get_font_size(&screen_size, 4), egui::FontFamily::Proportional)).strong()
-                                                );
-                                            });
-                                            ui.add_space(8.0);
-                                            ui.vertical(|ui| {
-                                                ui.label(
-                                                    egui::RichText::new("Fullstack developer / backend enthusiast").font(egui::FontId::new(get_font_size(&screen_size, 1), egui::FontFamily::Proportional)).strong()
-                                                );
-                                                socials(ui, "github/@ZeroUni", "https://github.com/ZeroUni", &None, get_font_size(&screen_size, 1));
-                                                socials(ui, "linkedin/@ZeroUni", "https://www.linkedin.com/in/ZeroUni", &None, get_font_size(&screen_size, 1));
-                                            });
-                                        });
-                                    }
-                                    let opening_rect = opener.allocate_space(ui).rect;
-                                    // Paint a transparent gray gradient before painting the contents
-                                    paint_angular_gradient(ui.painter(), opening_rect.expand2(vec2(8.0, 0.0)), Color32::from_rgba_unmultiplied(100, 100, 100, 50), Color32::TRANSPARENT, 1., vec2(2.0, 0.8));
-                                    opener.paint(ui);
-
-                                    ui.horizontal_wrapped(|ui| {
-                                        ui.set_max_width(opening_rect.width());
-                                        for skill in self.data.skills() {
-                                            skill_frameplate(ui, &skill.name, skill.color(), skill.text_color(), get_font_size(&screen_size, 0));
-                                        }
-                                    });
-
-                                    ui.horizontal(|ui| {
-                                        ui.visuals_mut().hyperlink_color = Color32::from_rgb(128, 36, 133);
-                                        ui.add(egui::github_link_file!(
-                                            "https://github.com/ZeroUni/portfolio/blob/main/",
-                                            "Source Code"
-                                        ).open_in_new_tab(true));
-                                        ui.visuals_mut().hyperlink_color = Color32::from_rgb(98, 36, 208);
-                                        ui.add(egui::github_link_file!(
-                                            "https://github.com/emilk/eframe_template/blob/main/",
-                                            "[egui]"
-                                        ).open_in_new_tab(true));
-                                    });
-                                });
-                            });
-                        }).response.rect;
-
-                        let (highlight_space, highlight_layout) = match screen_size {
-                            ScreenSize::Small | ScreenSize::Medium => (ui.allocate_rect(Rect::from_min_size(main_space.left_bottom() + vec2(0.0, 16.0), vec2(ui.available_width(), 200.0)), Sense::click()),
-                            egui::Layout::top_down(egui::Align::LEFT)),
-                            ScreenSize::Large => (ui.allocate_rect(Rect::from_min_size(main_space.right_top() + vec2(8.0, 0.0), vec2(ui.max_rect().width() - main_space.width() - 8.0, 200.0)), Sense::hover()),
-                            egui::Layout::top_down(egui::Align::Max)),
-                        };
-
-                        ui.scope_builder(egui::UiBuilder::default().max_rect(highlight_space.rect).sense(Sense::click()).layout(highlight_layout), |ui| {
-                            let outer_frame = egui::Frame::group(ui.style()).fill(Color32::from_gray(40).gamma_multiply_u8(127).blend(ui.visuals().extreme_bg_color.gamma_multiply_u8(100))).outer_margin(egui::Margin::symmetric(8, 0));
-                            outer_frame.show(ui, |ui| {
-                                ui.horizontal(|ui| {
-                                    ui.set_max_width(800.0_f32.min(highlight_space.rect.width()) - 16.0);
-                                    ui.with_layout(egui::Layout::left_to_right(egui::Align::TOP), |ui| {
-                                        ui.add_space(12.0);
-                                        ui.heading(egui::RichText::new("Highlights").underline());
-                                    });
-                                });
-                                let root_url = self.root_url.to_owned();
-                                ui.set_max_width(1100.0_f32.min(highlight_space.rect.width()) - 16.0);
-                                let max_len = self.data.project_highlights().len() - 1;
-                                for (idx, project) in self.data.project_highlights_mut().iter_mut().enumerate() {
-                                    add_highlighted_project(ui, ctx, &root_url, project);
+                        outer.set_min_height(outer.available_height());
+                        outer.set_width(outer.available_rect_before_wrap().width());
+                        // Lay the page out in a centered column, keeping lines readable on wide screens
+                        let available = outer.available_rect_before_wrap();
+                        let column_width = available.width().min(self.max_content_width);
+                        let column_rect = Rect::from_center_size(available.center(), vec2(column_width, available.height()));
+                        let mut column = outer.new_child(UiBuilder::new().max_rect(column_rect).layout(*outer.layout()));
+                        let ui = &mut column;
+                        ui.set_min_height(column_rect.height());
+
+                        // Sections render in the order `data.toml` lists them
+                        let mut previous: Option<(PageSection, Rect)> = None;
+                        let mut intro_registered = false;
+                        let mut intro_width = None;
+                        let sections = self.data.page_sections();
+                        for (idx, &section) in sections.iter().enumerate() {
+                            let rect = match section {
+                                PageSection::Hero => {
+                                    let rect = self.hero_section(ui, screen_size);
                                     ui.add_space(8.0);
-                                    if idx < max_len {
-                                        ui.separator();
-                                        ui.add_space(8.0);
+                                    rect
+                                }
+                                PageSection::About => {
+                                    // Skills listed right after the bio share its column
+                                    let with_skills = sections.get(idx + 1) == Some(&PageSection::Skills);
+                                    let rect = self.about_section(ui, screen_size, with_skills);
+                                    intro_width = Some(rect.width());
+                                    rect
+                                }
+                                PageSection::Skills => {
+                                    if previous.is_some_and(|(previous, _)| previous == PageSection::About) {
+                                        continue;
                                     }
+                                    let max_width = intro_width.unwrap_or(ui.available_width());
+                                    ui.scope(|ui| self.skills_list(ui, screen_size, max_width)).response.rect
                                 }
-                            });
-                        });
-
-                        let contact_frame = egui::Frame::group(ui.style())
-                            .fill(Color32::from_gray(40).gamma_multiply_u8(127).blend(ui.visuals().extreme_bg_color.gamma_multiply_u8(100)))
-                            .outer_margin(egui::Margin::symmetric(8, 4));
-                        
-                        contact_frame.show(ui, |ui| {
-                            ui.horizontal(|ui| {
-                                ui.set_max_width(main_space.width());
-                                ui.with_layout(egui::Layout::left_to_right(egui::Align::TOP), |ui| {
-                                    ui.heading(egui::RichText::new("Contact Me:").underline());
-                                });
-                                ui.hyperlink_to("[email]", "mailto:zd.muhs@gmail.com");
-                            });
-                        });
+                                PageSection::Projects => {
+                                    // On wide screens the projects sit beside the bio rather than below it
+                                    let beside = previous.filter(|(previous, _)| *previous == PageSection::About).map(|(_, rect)| rect);
+                                    self.projects_section(ui, ctx, screen_size, beside, previous.map(|(_, rect)| rect))
+                                }
+                                PageSection::Timeline => {
+                                    if self.data.timeline.is_empty() {
+                                        continue;
+                                    }
+                                    self.timeline_section(ui, screen_size)
+                                }
+                                PageSection::Contact => {
+                                    let width = intro_width.unwrap_or(ui.available_width().min(800.0));
+                                    self.contact_section(ui, ctx, width)
+                                }
+                            };
+                            // The first of the hero, bio and skills is where "About" jumps to
+                            if !intro_registered && matches!(section, PageSection::Hero | PageSection::About | PageSection::Skills) {
+                                register_section(ui, Section::Intro.title(), rect);
+                                intro_registered = true;
+                            }
+                            previous = Some((section, rect));
+                        }
 
-                        ui.with_layout(egui::Layout::bottom_up(egui::Align::LEFT), |ui| {
-                            powered_by_egui_and_eframe(ui);
-                            egui::warn_if_debug_build(ui);
-                        });
+                        if self.footer(ui) {
+                            #[cfg(debug_assertions)]
+                            {
+                                self.data_reload.error = None;
+                                self.fetch_data(ui.ctx());
+                            }
+                        }
+                        outer.advance_cursor_after_rect(column.min_rect());
+                    });
+                    let scroll_response = scroll_output.inner_rect;
+                    if !self.print_mode {
+                        let palette = self.palette(ctx);
+                        scroll_progress(ctx, &scroll_output, (palette.accent, ui.visuals().hyperlink_color));
+                    }
 
-                    }).inner_rect;
+                    if cfg!(debug_assertions) && self.debug_overlay {
+                        let labels_rect = Rect::from_min_size(ui.clip_rect().right_top() + vec2(-260.0, 4.0), vec2(256.0, 40.0));
+                        ui.put(labels_rect, Label::new(egui::RichText::new(format!("Scene Rect: {:?}\nClip Rect: {:?}", self.scene_rect, scroll_response)).small().monospace()));
+                    }
 
                     // If the scene_rect has negative bounds (x or y), shift it to the origin preserving the size.
                     if self.scene_rect.min.x < 0.0 || self.scene_rect.min.y < 0.0 {
@@ -492,6 +1218,10 @@ impl eframe::App for TemplateApp {
                     }
                 });
         });
+
+        self.toasts.show(ctx);
+        self.show_loading_splash(ctx);
+        self.print_when_ready(ctx);
     }
 }
 
@@ -509,15 +1239,74 @@ fn powered_by_egui_and_eframe(ui: &mut egui::Ui) {
     });
 }
 
+/// Layout tier of the window, so components can adapt without repeating the width thresholds.
 #[repr(u8)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum ScreenSize {
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScreenSize {
     Small = 1,
     Medium = 2,
+    #[default]
     Large = 3,
 }
 
+/// The minimum unzoomed widths at which each [`ScreenSize`] tier starts, configurable as `[breakpoints]` in `data.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Breakpoints {
+    pub medium: f32,
+    pub large: f32,
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self {
+            medium: 768.0,
+            large: 1028.0,
+        }
+    }
+}
+
+impl Breakpoints {
+    fn id() -> Id {
+        Id::new("breakpoints")
+    }
+
+    /// Makes these the breakpoints [`ScreenSize::current`] uses from now on.
+    /// - `ctx`: The egui context to store them in.
+    pub fn install(self, ctx: &egui::Context) {
+        ctx.data_mut(|data| data.insert_temp(Self::id(), self));
+    }
+
+    /// The breakpoints last installed with [`Breakpoints::install`], the defaults unless set.
+    pub fn current(ctx: &egui::Context) -> Self {
+        ctx.data(|data| data.get_temp(Self::id())).unwrap_or_default()
+    }
+}
+
 impl ScreenSize {
+    /// The tier for a window of the given width.
+    /// - `width`: The window width in unzoomed points, see [`ScreenSize::width`].
+    /// - `breakpoints`: Where each tier starts.
+    pub fn from_width(width: f32, breakpoints: &Breakpoints) -> Self {
+        if width < breakpoints.medium {
+            Self::Small
+        } else if width < breakpoints.large {
+            Self::Medium
+        } else {
+            Self::Large
+        }
+    }
+
+    /// The tier of the current window, using the [`Breakpoints`] from `data.toml`, see [`Breakpoints::current`].
+    pub fn current(ctx: &egui::Context) -> Self {
+        Self::from_width(Self::width(ctx), &Breakpoints::current(ctx))
+    }
+
+    /// The window width with the zoom factor undone, so tiers don't shift when the app zooms itself.
+    pub fn width(ctx: &egui::Context) -> f32 {
+        ctx.screen_rect().width() * ctx.zoom_factor()
+    }
+
     fn from_u8(value: u8) -> Option<Self> {
         match value {
             1 => Some(ScreenSize::Small),
@@ -544,16 +1333,281 @@ impl ScreenSize {
     }
 }
 
+/// A value that only propagates once it has been stable for a given delay.
+/// Bind widgets to [`Debounced::pending_mut`] and read the settled value with [`Debounced::value`].
+struct Debounced<T> {
+    pending: T,
+    value: T,
+    delay: std::time::Duration,
+    changed_at: Option<f64>, // Input time of the last change to `pending`, if not yet settled
+}
+
+impl<T: Clone + PartialEq> Debounced<T> {
+    /// Create a new debounced value that settles after `delay_ms` milliseconds without changes.
+    fn new(value: T, delay_ms: u64) -> Self {
+        Self {
+            pending: value.clone(),
+            value,
+            delay: std::time::Duration::from_millis(delay_ms),
+            changed_at: None,
+        }
+    }
+
+    /// The latest (possibly unsettled) value, for binding to input widgets.
+    fn pending_mut(&mut self) -> &mut T {
+        &mut self.pending
+    }
+
+    /// The settled value.
+    fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Restart the delay, call this whenever the pending value is edited.
+    fn mark_changed(&mut self, ctx: &egui::Context) {
+        self.changed_at = Some(now(ctx));
+        ctx.request_repaint_after(self.delay);
+    }
+
+    /// Propagate the pending value once it has been stable for the delay.
+    /// Returns `true` on the frame the settled value changes.
+    fn tick(&mut self, ctx: &egui::Context) -> bool {
+        let Some(changed_at) = self.changed_at else {
+            return false;
+        };
+        let elapsed = now(ctx) - changed_at;
+        if elapsed < self.delay.as_secs_f64() {
+            // Wake up again once the remaining delay has passed
+            ctx.request_repaint_after(self.delay.saturating_sub(std::time::Duration::from_secs_f64(elapsed.max(0.0))));
+            return false;
+        }
+        self.changed_at = None;
+        if self.value == self.pending {
+            return false;
+        }
+        self.value = self.pending.clone();
+        true
+    }
+}
+
+/// Bookkeeping for [`TemplateApp::print_mode`].
+#[derive(Default)]
+struct PrintState {
+    print_at: Option<f64>, // Set by the Print button, the time at which to open the print dialog
+    browser_printing: Arc<AtomicBool>, // Between the browser's `beforeprint` and `afterprint` events
+    listening: bool,
+    theme_before_print: Option<egui::ThemePreference>, // Restored when print mode ends
+}
+
+/// Mirrors the browser's `beforeprint`/`afterprint` events into `printing`, repainting on each.
+/// Without a browser window this does nothing.
+/// - `ctx`: The egui context to repaint.
+/// - `printing`: Set while the browser is printing.
+fn listen_for_print_events(ctx: &egui::Context, printing: &Arc<AtomicBool>) {
+    let Some(window) = window() else {
+        return;
+    };
+    for (event, value) in [("beforeprint", true), ("afterprint", false)] {
+        let ctx = ctx.clone();
+        let printing = printing.clone();
+        let listener = Closure::<dyn FnMut()>::new(move || {
+            printing.store(value, Ordering::Relaxed);
+            ctx.request_repaint();
+        });
+        if let Err(error) = window.add_event_listener_with_callback(event, listener.as_ref().unchecked_ref()) {
+            log::warn!("Failed to listen for {event}: {error:?}");
+        }
+        // The listeners live as long as the page
+        listener.forget();
+    }
+}
+
+/// Bookkeeping for [`TemplateApp::reload_data_on_focus`].
+#[cfg(debug_assertions)]
+#[derive(Default)]
+struct DataReload {
+    focused: bool, // Whether the window had focus last frame
+    fetched: Arc<std::sync::Mutex<Option<Result<Data, String>>>>, // Set by the fetch callback, taken on the next frame
+    source: DataSource, // Where the data on screen came from
+    error: Option<String>, // Why the last fetch failed, cleared by a successful one or a retry
+}
+
+/// Where the data on screen was loaded from.
+#[cfg(debug_assertions)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum DataSource {
+    #[default]
+    Embedded, // The `data.toml` baked into the binary
+    Remote, // A copy fetched from the server
+}
+
+/// Bookkeeping for [`TemplateApp::crossfade_theme`].
+#[derive(Default)]
+struct ThemeTransition {
+    shown_theme: Option<Theme>, // The theme of the previous frame, to notice switches from anywhere
+    from: Option<egui::Visuals>, // The colors on screen when the switch happened
+    target: Option<(Theme, Style)>, // The untouched style of the theme being faded to
+}
+
+/// Interpolates the colors that make up most of the page, taking everything else from `to`.
+/// - `from`: The visuals at `t = 0`.
+/// - `to`: The visuals at `t = 1`.
+/// - `t`: Progress of the blend, from `0.0` to `1.0`.
+fn blend_visuals(from: &egui::Visuals, to: &egui::Visuals, t: f32) -> egui::Visuals {
+    let lerp = |from: Color32, to: Color32| from.lerp_to_gamma(to, t);
+    let mut visuals = to.clone();
+    visuals.override_text_color = match (from.override_text_color, to.override_text_color) {
+        (Some(from), Some(to)) => Some(lerp(from, to)),
+        (_, to) => to,
+    };
+    visuals.panel_fill = lerp(from.panel_fill, to.panel_fill);
+    visuals.window_fill = lerp(from.window_fill, to.window_fill);
+    visuals.window_stroke.color = lerp(from.window_stroke.color, to.window_stroke.color);
+    visuals.extreme_bg_color = lerp(from.extreme_bg_color, to.extreme_bg_color);
+    visuals.hyperlink_color = lerp(from.hyperlink_color, to.hyperlink_color);
+    visuals.selection.bg_fill = lerp(from.selection.bg_fill, to.selection.bg_fill);
+    let widget_pairs = [
+        (&from.widgets.noninteractive, &mut visuals.widgets.noninteractive),
+        (&from.widgets.inactive, &mut visuals.widgets.inactive),
+        (&from.widgets.hovered, &mut visuals.widgets.hovered),
+        (&from.widgets.active, &mut visuals.widgets.active),
+        (&from.widgets.open, &mut visuals.widgets.open),
+    ];
+    for (from, widget) in widget_pairs {
+        widget.bg_fill = lerp(from.bg_fill, widget.bg_fill);
+        widget.weak_bg_fill = lerp(from.weak_bg_fill, widget.weak_bg_fill);
+        widget.bg_stroke.color = lerp(from.bg_stroke.color, widget.bg_stroke.color);
+        widget.fg_stroke.color = lerp(from.fg_stroke.color, widget.fg_stroke.color);
+    }
+    visuals
+}
+
+/// Whether the app is still waiting on its primary assets before revealing the page.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum LoadState {
+    #[default]
+    Initializing,
+    Ready,
+}
+
+/// The page sections the number key shortcuts jump to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Section {
+    Intro,
+    Highlights,
+    Contact,
+}
+
+impl Section {
+    const ALL: [Self; 3] = [Self::Intro, Self::Highlights, Self::Contact];
+
+    /// The title of the section, as registered with [`register_section`].
+    fn title(self) -> &'static str {
+        match self {
+            Self::Intro => "About",
+            Self::Highlights => "Highlights",
+            Self::Contact => "Contact Me",
+        }
+    }
+
+    /// The route pointing at this section.
+    fn route(self) -> Route {
+        match self {
+            Self::Intro => Route::Home,
+            Self::Highlights => Route::Projects,
+            Self::Contact => Route::Contact,
+        }
+    }
+
+    /// The section a route scrolls to, or `None` for routes shown in an overlay.
+    fn for_route(route: &Route) -> Option<Self> {
+        match route {
+            // The skills are listed in the intro
+            Route::Home | Route::Skills => Some(Self::Intro),
+            Route::Projects => Some(Self::Highlights),
+            Route::Contact => Some(Self::Contact),
+            Route::Project(_) => None,
+        }
+    }
+
+    /// The number key jumping to this section, in page order.
+    fn key(self) -> egui::Key {
+        match self {
+            Self::Intro => egui::Key::Num1,
+            Self::Highlights => egui::Key::Num2,
+            Self::Contact => egui::Key::Num3,
+        }
+    }
+
+    /// Hover text describing the shortcut to this section, e.g. `Press 2 to jump here`.
+    fn shortcut_hint(self, ctx: &egui::Context) -> String {
+        let shortcut = egui::KeyboardShortcut::new(egui::Modifiers::NONE, self.key());
+        format!("Press {} to jump here", ctx.format_shortcut(&shortcut))
+    }
+}
+
+/// The theme picked in the top bar, persisted across visits.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum ThemeChoice {
+    #[default]
+    System, // Follows `prefers-color-scheme`, re-derived every frame rather than stored
+    Light,
+    Dark,
+    HighContrast, // Shown in place of the dark theme, see [`TemplateApp::get_high_contrast_style`]
+}
+
+impl ThemeChoice {
+    const ALL: [Self; 4] = [Self::System, Self::Light, Self::Dark, Self::HighContrast];
+
+    /// Accessible label and hover text of the choice's button.
+    fn label(self) -> &'static str {
+        match self {
+            Self::System => "System theme",
+            Self::Light => "Light theme",
+            Self::Dark => "Dark theme",
+            Self::HighContrast => "High contrast theme",
+        }
+    }
+
+    /// Icon of the choice's button, drawn in white for [`egui::Image::tint`].
+    fn icon(self) -> ImageSource<'static> {
+        match self {
+            Self::System => include_image!("../assets/icons/theme-system.svg"),
+            Self::Light => include_image!("../assets/icons/theme-light.svg"),
+            Self::Dark => include_image!("../assets/icons/theme-dark.svg"),
+            Self::HighContrast => include_image!("../assets/icons/theme-high-contrast.svg"),
+        }
+    }
+}
+
 #[derive(PartialEq)]
 enum AnimateDirection {
     In,
     Out,
 }
 
+/// Whether the browser asks for reduced motion through the `prefers-reduced-motion` media query.
+pub fn prefers_reduced_motion() -> bool {
+    window()
+        .and_then(|win| win.match_media("(prefers-reduced-motion: reduce)").ok().flatten())
+        .is_some_and(|query| query.matches())
+}
+
+/// The theme the browser asks for through the `prefers-color-scheme` media query, `None` outside the browser.
+pub fn prefers_color_scheme() -> Option<Theme> {
+    window()
+        .and_then(|win| win.match_media("(prefers-color-scheme: dark)").ok().flatten())
+        .map(|query| if query.matches() { Theme::Dark } else { Theme::Light })
+}
+
+/// The url assets are fetched relative to: the document's base uri in the browser.
+/// Native builds have no document, so they read it from the `PORTFOLIO_BASE_URL` environment variable instead
+/// (e.g. `http://127.0.0.1:8080/` while `trunk serve` is running), or go without assets when it is unset.
 pub fn get_base_url() -> String {
     window()
         .and_then(|win| win.document())
         .and_then(|doc| doc.base_uri().ok().flatten())
+        .or_else(|| std::env::var("PORTFOLIO_BASE_URL").ok())
         .unwrap_or_else(|| "".to_string())
 }
 
@@ -579,4 +1633,114 @@ pub fn get_font_size(screen_size: &ScreenSize, paragraph_type: u8) -> f32 {
         4 => base_size * 1.8,
         _ => base_size,
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::{CornerRadius, TextStyle};
+    use crate::elements::last_pass_widgets;
+
+    /// The invariants both hand-built themes share, so accidental edits to either show up here.
+    fn assert_theme_invariants(name: &str, style: &Style, panel_fill: Color32) {
+        assert_eq!(style.visuals.panel_fill, panel_fill, "{name}: panel fill");
+        assert_eq!(style.visuals.extreme_bg_color, panel_fill, "{name}: text edits sit on the panel color");
+        let sizes: Vec<(TextStyle, f32)> = [TextStyle::Heading, TextStyle::Body, TextStyle::Monospace, TextStyle::Button, TextStyle::Small]
+            .into_iter()
+            .map(|text_style| {
+                let size = style.text_styles.get(&text_style).map_or(0.0, |font| font.size);
+                (text_style, size)
+            })
+            .collect();
+        assert_eq!(sizes, [
+            (TextStyle::Heading, 22.0),
+            (TextStyle::Body, 18.0),
+            (TextStyle::Monospace, 16.0),
+            (TextStyle::Button, 18.0),
+            (TextStyle::Small, 14.0),
+        ], "{name}: text style sizes");
+        assert_eq!(style.visuals.window_corner_radius, CornerRadius::same(6), "{name}: window corner radius");
+        assert_eq!(style.visuals.widgets.noninteractive.corner_radius, CornerRadius::same(4), "{name}: widget corner radius");
+        assert_eq!(style.spacing.button_padding, vec2(2.0, 2.0), "{name}: button padding");
+        assert_eq!(style.spacing.window_margin, Margin::same(4), "{name}: window margin");
+        assert!(style.spacing.scroll.floating, "{name}: scroll bars float over the content");
+        assert_eq!(style.spacing.scroll.bar_width, 8.0, "{name}: scroll bar width");
+    }
+
+    #[test]
+    fn dark_theme_keeps_its_tuned_visuals() {
+        let style = TemplateApp::get_dark_theme_style(&egui::Context::default(), &Palette::dark());
+        assert!(style.visuals.dark_mode);
+        assert_theme_invariants("dark", &style, Color32::from_rgb(16, 17, 18));
+    }
+
+    #[test]
+    fn light_theme_keeps_its_tuned_visuals() {
+        let style = TemplateApp::get_light_theme_style(&egui::Context::default(), &Palette::light());
+        assert!(!style.visuals.dark_mode);
+        assert_theme_invariants("light", &style, Color32::from_rgb(202, 233, 255));
+    }
+
+    #[test]
+    fn screen_size_follows_the_installed_breakpoints() {
+        let ctx = egui::Context::default();
+        let tier_at = |width: f32| {
+            let mut tier = None;
+            let input = egui::RawInput { screen_rect: Some(Rect::from_min_size(egui::Pos2::ZERO, vec2(width, 600.0))), ..Default::default() };
+            let _: egui::FullOutput = ctx.run(input, |ctx| tier = Some(ScreenSize::current(ctx)));
+            tier
+        };
+        assert_eq!(tier_at(800.0), Some(ScreenSize::Medium));
+        Breakpoints { medium: 600.0, large: 760.0 }.install(&ctx);
+        assert_eq!(tier_at(500.0), Some(ScreenSize::Small));
+        assert_eq!(tier_at(700.0), Some(ScreenSize::Medium));
+        assert_eq!(tier_at(800.0), Some(ScreenSize::Large));
+    }
+
+    #[test]
+    fn high_contrast_theme_keeps_its_tuned_visuals() {
+        let style = TemplateApp::get_high_contrast_style(&egui::Context::default());
+        assert!(style.visuals.dark_mode);
+        assert_theme_invariants("high contrast", &style, Color32::BLACK);
+        assert_eq!(style.visuals.override_text_color, Some(Color32::WHITE));
+        let widgets = &style.visuals.widgets;
+        for (state, visuals) in [("noninteractive", &widgets.noninteractive), ("inactive", &widgets.inactive), ("hovered", &widgets.hovered), ("active", &widgets.active)] {
+            assert!(visuals.bg_stroke.width >= 2.0 && visuals.fg_stroke.width >= 2.0, "{state}: strokes should be thick");
+        }
+        // Focus rings take the hovered foreground, which must stand out against the black page
+        assert_eq!(widgets.hovered.fg_stroke.color, Color32::from_rgb(255, 255, 0));
+    }
+
+    #[test]
+    fn high_contrast_takes_the_dark_theme_slot_until_deselected() {
+        let ctx = egui::Context::default();
+        let mut app = TemplateApp { theme_choice: ThemeChoice::HighContrast, ..Default::default() };
+        app.sync_theme(&ctx);
+        assert_eq!(ctx.theme(), Theme::Dark);
+        assert_eq!(ctx.style_of(Theme::Dark).visuals.panel_fill, Color32::BLACK);
+
+        app.theme_choice = ThemeChoice::Dark;
+        app.sync_theme(&ctx);
+        assert_eq!(ctx.style_of(Theme::Dark).visuals.panel_fill, app.data.palette.dark.primary_bg);
+    }
+
+    #[test]
+    fn narrow_viewport_does_not_overflow() {
+        let ctx = egui::Context::default();
+        let mut app = TemplateApp::new(&eframe::CreationContext::_new_kittest(ctx.clone()));
+        let mut frame = eframe::Frame::_new_kittest();
+        // Let the layout tier settle, and images and reveal animations resolve
+        for pass in 0..20 {
+            // Like the integrations, report the window in points, which the small tier's zoom changes
+            let screen_rect = Rect::from_min_size(egui::Pos2::ZERO, vec2(320.0, 640.0) / ctx.zoom_factor());
+            let input = egui::RawInput { screen_rect: Some(screen_rect), time: Some(f64::from(pass) * 0.1), ..Default::default() };
+            let _: egui::FullOutput = ctx.run(input, |ctx| eframe::App::update(&mut app, ctx, &mut frame));
+        }
+        let screen_rect = ctx.screen_rect();
+        let overflowing: Vec<(egui::Id, Rect)> = last_pass_widgets(&ctx).iter()
+            .filter(|widget| widget.rect.is_finite() && widget.rect.is_positive())
+            .filter(|widget| widget.rect.min.x < screen_rect.min.x - 0.5 || widget.rect.max.x > screen_rect.max.x + 0.5)
+            .map(|widget| (widget.id, widget.rect))
+            .collect();
+        assert!(overflowing.is_empty(), "widgets overflow a 320px wide window: {overflowing:?}");
+    }
+}
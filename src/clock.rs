@@ -0,0 +1,202 @@
+use std::sync::Arc;
+
+/// Where the animation helpers read the current time from, see [`now`].
+/// Tests install a [`FakeClock`] to step animations to exact instants.
+pub trait Clock: Send + Sync {
+    /// The current time in seconds, on the same scale as `egui::InputState::time`.
+    fn now(&self, ctx: &egui::Context) -> f64;
+}
+
+/// Reads the frame's input time, used unless another clock is installed.
+pub struct InputClock;
+
+impl Clock for InputClock {
+    fn now(&self, ctx: &egui::Context) -> f64 {
+        ctx.input(|i| i.time)
+    }
+}
+
+fn clock_id() -> egui::Id {
+    egui::Id::new("clock")
+}
+
+/// Installs the clock that [`now`] reads from now on.
+pub fn set_clock(ctx: &egui::Context, clock: Arc<dyn Clock>) {
+    ctx.data_mut(|data| data.insert_temp(clock_id(), clock));
+}
+
+/// The current time in seconds from the installed clock, falling back to [`InputClock`].
+pub fn now(ctx: &egui::Context) -> f64 {
+    match ctx.data(|data| data.get_temp::<Arc<dyn Clock>>(clock_id())) {
+        Some(clock) => clock.now(ctx),
+        None => InputClock.now(ctx),
+    }
+}
+
+/// Progress of an [`animate_bool`] animation, kept in the context's temp data.
+#[derive(Clone, Copy)]
+struct BoolAnimation {
+    value: bool,
+    toggled_at: f64,
+    from: f32, // Where the animation was when it last toggled
+}
+
+impl BoolAnimation {
+    fn progress(&self, now: f64, duration: f32) -> f32 {
+        let target = if self.value { 1.0 } else { 0.0 };
+        // Reversing halfway only takes half the time
+        let span = f64::from(duration * (target - self.from).abs());
+        if span <= 0.0 {
+            return target;
+        }
+        let t = ((now - self.toggled_at) / span).clamp(0.0, 1.0) as f32;
+        egui::lerp(self.from..=target, t)
+    }
+}
+
+/// Linearly animates from `0.0` to `1.0` and back as `value` toggles, timed by [`now`].
+///
+/// Like `egui::Context::animate_bool_with_time`, the first call snaps to the value; apply easing to the result.
+/// - `ctx`: The egui context holding the animation's state.
+/// - `id`: A stable id for the animation.
+/// - `value`: The state to animate towards.
+/// - `duration`: How long a full animation takes, in seconds. Zero snaps to the value.
+pub fn animate_bool(ctx: &egui::Context, id: egui::Id, value: bool, duration: f32) -> f32 {
+    let now = now(ctx);
+    let state = ctx.data(|data| data.get_temp::<BoolAnimation>(id));
+    let state = match state {
+        Some(state) if state.value == value => state,
+        Some(state) => BoolAnimation { value, toggled_at: now, from: state.progress(now, duration) },
+        None => BoolAnimation { value, toggled_at: now, from: if value { 1.0 } else { 0.0 } },
+    };
+    ctx.data_mut(|data| data.insert_temp(id, state));
+    let progress = state.progress(now, duration);
+    if progress != if value { 1.0 } else { 0.0 } {
+        ctx.request_repaint();
+    }
+    progress
+}
+
+/// Progress of an [`animate_value`] animation, kept in the context's temp data.
+#[derive(Clone, Copy)]
+struct ValueAnimation {
+    from: f32,
+    to: f32,
+    changed_at: f64, // When `to` last changed
+}
+
+impl ValueAnimation {
+    fn value(&self, now: f64, duration: f32) -> f32 {
+        if duration <= 0.0 {
+            return self.to;
+        }
+        let t = ((now - self.changed_at) / f64::from(duration)).clamp(0.0, 1.0) as f32;
+        egui::lerp(self.from..=self.to, t)
+    }
+}
+
+/// Linearly animates towards `target`, timed by [`now`].
+///
+/// Like `egui::Context::animate_value_with_time`, the first call snaps to `target`. When `target` changes, the
+/// animation restarts from wherever it is; apply easing to the result.
+/// - `ctx`: The egui context holding the animation's state.
+/// - `id`: A stable id for the animation.
+/// - `target`: The value to animate towards.
+/// - `duration`: How long getting to a new target takes, in seconds. Zero snaps to the target.
+pub fn animate_value(ctx: &egui::Context, id: egui::Id, target: f32, duration: f32) -> f32 {
+    let now = now(ctx);
+    let state = match ctx.data(|data| data.get_temp::<ValueAnimation>(id)) {
+        Some(state) if state.to == target => state,
+        Some(state) if duration > 0.0 => ValueAnimation { from: state.value(now, duration), to: target, changed_at: now },
+        _ => ValueAnimation { from: target, to: target, changed_at: now },
+    };
+    ctx.data_mut(|data| data.insert_temp(id, state));
+    let value = state.value(now, duration);
+    if value != target {
+        ctx.request_repaint();
+    }
+    value
+}
+
+/// A clock that only moves when told to, for stepping animations in tests.
+#[cfg(test)]
+#[derive(Default)]
+pub struct FakeClock {
+    time: std::sync::Mutex<f64>,
+}
+
+#[cfg(test)]
+impl FakeClock {
+    /// Creates a clock at time zero and installs it on `ctx`.
+    pub fn install(ctx: &egui::Context) -> Arc<Self> {
+        let clock = Arc::new(Self::default());
+        set_clock(ctx, clock.clone());
+        clock
+    }
+
+    /// Moves the clock forward.
+    /// - `seconds`: How far to move it.
+    pub fn advance(&self, seconds: f64) {
+        *self.time.lock().unwrap_or_else(std::sync::PoisonError::into_inner) += seconds;
+    }
+}
+
+#[cfg(test)]
+impl Clock for FakeClock {
+    fn now(&self, _ctx: &egui::Context) -> f64 {
+        *self.time.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn animations_follow_the_installed_clock() {
+        let ctx = egui::Context::default();
+        let clock = FakeClock::install(&ctx);
+        let id = egui::Id::new("fade");
+        assert_eq!(animate_bool(&ctx, id, false, 0.4), 0.0, "the first call snaps");
+
+        assert_eq!(animate_bool(&ctx, id, true, 0.4), 0.0);
+        clock.advance(0.1);
+        assert!((animate_bool(&ctx, id, true, 0.4) - 0.25).abs() < 1e-6);
+        clock.advance(0.1);
+        assert!((animate_bool(&ctx, id, true, 0.4) - 0.5).abs() < 1e-6);
+
+        // Reversing starts from where the animation is, and takes as long as the way back
+        assert!((animate_bool(&ctx, id, false, 0.4) - 0.5).abs() < 1e-6);
+        clock.advance(0.1);
+        assert!((animate_bool(&ctx, id, false, 0.4) - 0.25).abs() < 1e-6);
+        clock.advance(0.1);
+        assert_eq!(animate_bool(&ctx, id, false, 0.4), 0.0);
+        clock.advance(1.0);
+        assert_eq!(animate_bool(&ctx, id, false, 0.4), 0.0);
+    }
+
+    #[test]
+    fn values_restart_from_where_they_are() {
+        let ctx = egui::Context::default();
+        let clock = FakeClock::install(&ctx);
+        let id = egui::Id::new("height");
+        assert_eq!(animate_value(&ctx, id, 10.0, 0.4), 10.0, "the first call snaps");
+
+        assert_eq!(animate_value(&ctx, id, 30.0, 0.4), 10.0);
+        clock.advance(0.2);
+        assert!((animate_value(&ctx, id, 30.0, 0.4) - 20.0).abs() < 1e-4);
+        // A new target takes the full duration from the current value
+        assert!((animate_value(&ctx, id, 0.0, 0.4) - 20.0).abs() < 1e-4);
+        clock.advance(0.1);
+        assert!((animate_value(&ctx, id, 0.0, 0.4) - 15.0).abs() < 1e-4);
+        assert_eq!(animate_value(&ctx, id, 5.0, 0.0), 5.0, "a zero duration snaps");
+        assert_eq!(animate_value(&ctx, id, 5.0, 0.4), 5.0);
+    }
+
+    #[test]
+    fn input_time_is_the_default() {
+        let ctx = egui::Context::default();
+        let input = egui::RawInput { time: Some(12.5), ..Default::default() };
+        let _: egui::FullOutput = ctx.run(input, |ctx| assert_eq!(now(ctx), 12.5));
+    }
+}
@@ -0,0 +1,52 @@
+use egui::{Color32, Rgba};
+
+/// The WCAG relative luminance of a color, computed in linear light, from `0.0` (black) to `1.0` (white).
+pub fn relative_luminance(color: Color32) -> f32 {
+    let linear = Rgba::from(color);
+    0.2126 * linear.r() + 0.7152 * linear.g() + 0.0722 * linear.b()
+}
+
+/// The WCAG contrast ratio between two opaque colors, from `1.0` (identical) to `21.0` (black on white).
+pub fn contrast_ratio(a: Color32, b: Color32) -> f32 {
+    let (lighter, darker) = if relative_luminance(a) > relative_luminance(b) { (a, b) } else { (b, a) };
+    (relative_luminance(lighter) + 0.05) / (relative_luminance(darker) + 0.05)
+}
+
+/// Black or white, whichever is more legible on the given background.
+pub fn best_text_color(background: Color32) -> Color32 {
+    if contrast_ratio(background, Color32::BLACK) >= contrast_ratio(background, Color32::WHITE) {
+        Color32::BLACK
+    } else {
+        Color32::WHITE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contrast_matches_wcag_reference_pairs() {
+        let pairs = [
+            (Color32::BLACK, Color32::WHITE, 21.0),
+            (Color32::WHITE, Color32::WHITE, 1.0),
+            (Color32::from_gray(0x76), Color32::WHITE, 4.54), // The lightest gray passing AA on white
+            (Color32::from_rgb(0, 0, 255), Color32::WHITE, 8.59),
+            (Color32::from_rgb(255, 0, 0), Color32::WHITE, 4.0),
+            (Color32::from_rgb(0, 128, 0), Color32::WHITE, 5.14),
+        ];
+        for (a, b, expected) in pairs {
+            let ratio = contrast_ratio(a, b);
+            assert!((ratio - expected).abs() < 0.01, "{a:?} on {b:?}: {ratio:.2}, expected {expected}");
+            assert_eq!(ratio, contrast_ratio(b, a), "the ratio doesn't depend on the order");
+        }
+    }
+
+    #[test]
+    fn auto_contrast_picks_the_more_legible_text() {
+        assert_eq!(best_text_color(Color32::from_rgb(255, 62, 0)), Color32::BLACK);
+        assert_eq!(best_text_color(Color32::from_rgb(78, 64, 90)), Color32::WHITE);
+        assert_eq!(best_text_color(Color32::from_rgb(255, 153, 0)), Color32::BLACK, "white on orange is barely legible");
+        assert_eq!(best_text_color(Color32::from_rgb(0, 0, 255)), Color32::WHITE);
+    }
+}
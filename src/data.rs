@@ -1,37 +1,281 @@
 use core::fmt;
+use std::sync::OnceLock;
 
-use serde::{Deserialize, Serialize};
-use egui::{load::{SizedTexture, TexturePoll}, Color32};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::{app::Breakpoints, elements::{Easing, LinkTarget, ScrollbarVisibility}};
+use egui::{include_image, load::{SizedTexture, TexturePoll}, Color32, ImageSource};
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(try_from = "RawSkill")]
 pub struct Skill {
     pub name: String,
     pub rgb: [u8; 3],
     pub text_rgb: [u8; 3],
+    #[serde(default)]
+    pub icon: Option<String>, // Optional path to an icon shown before the name, relative to the root url
+    #[serde(default)]
+    pub level: Option<u8>, // Proficiency from 1 to 5, used to size the skill in the word cloud
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+/// A [`Skill`] as written in `data.toml`, before its colors are validated.
+#[derive(Deserialize)]
+struct RawSkill {
+    name: String,
+    rgb: RgbInput,
+    text_rgb: RgbInput,
+    #[serde(default)]
+    icon: Option<String>,
+    #[serde(default)]
+    level: Option<u8>,
+}
+
+/// A color as written in `data.toml`: either `[r, g, b]` or a `"#rrggbb"` hex string.
+/// Kept loose so [`RgbInput::parse`] can explain what is wrong instead of serde's type errors.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RgbInput {
+    Channels(Vec<i64>),
+    Hex(String),
+}
+
+impl RgbInput {
+    fn parse(self) -> Result<[u8; 3], String> {
+        match self {
+            Self::Channels(channels) => {
+                let [r, g, b] = channels[..] else {
+                    return Err(format!("expected 3 channels as [r, g, b], got {}", channels.len()));
+                };
+                let channel = |value: i64| u8::try_from(value).map_err(|err| format!("channels must be between 0 and 255, got {value} ({err})"));
+                Ok([channel(r)?, channel(g)?, channel(b)?])
+            }
+            Self::Hex(hex) => {
+                let digits = hex.strip_prefix('#').unwrap_or(&hex);
+                let invalid = || format!("expected a hex color like \"#ff8800\", got {hex:?}");
+                if digits.len() != 6 {
+                    return Err(invalid());
+                }
+                let value = u32::from_str_radix(digits, 16).map_err(|err| format!("{} ({err})", invalid()))?;
+                let [_, r, g, b] = value.to_be_bytes();
+                Ok([r, g, b])
+            }
+        }
+    }
+}
+
+impl TryFrom<RawSkill> for Skill {
+    type Error = String;
+
+    fn try_from(raw: RawSkill) -> Result<Self, Self::Error> {
+        let name = raw.name;
+        let field = |field: &str, input: RgbInput| input.parse().map_err(|err| format!("skill `{name}`: invalid `{field}`: {err}"));
+        if let Some(level) = raw.level.filter(|level| !Self::LEVELS.contains(level)) {
+            return Err(format!("skill `{name}`: invalid `level`: expected 1 to 5, got {level}"));
+        }
+        Ok(Self {
+            rgb: field("rgb", raw.rgb)?,
+            text_rgb: field("text_rgb", raw.text_rgb)?,
+            icon: raw.icon,
+            level: raw.level,
+            name,
+        })
+    }
+}
+
+/// A label on a project highlight.
+/// Colored like the skill of the same name, see [`Data::skill_color`], or neutral when no skill matches.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct Tag {
+    pub name: String,
+    #[serde(default)]
+    pub icon: Option<String>, // Optional path to an icon shown before the name, relative to the root url
+    #[serde(skip)]
+    colors: Option<(Color32, Color32)>, // Background and text color of the matching skill, resolved when the data loads
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Data {
+    #[serde(default)]
+    pub contact_endpoint: Option<String>, // URL the contact form posts to; the form is hidden when unset
+    #[serde(default)]
+    pub palette: Palettes, // Theme colors, falling back to the built-in palettes for any theme left out
+    #[serde(default)]
+    pub logo_path: Option<String>, // Path or URL of the brand logo; the bundled croissant is used when unset
+    #[serde(default)]
+    pub resume_path: Option<String>, // Path or URL of the downloadable résumé; the button is hidden when unset
+    #[serde(default)]
+    pub skill_order: SkillOrder, // Whether skills are listed as written or alphabetically, see [`Data::listed_skills`]
+    #[serde(default)]
+    pub link_target: LinkTarget, // Whether external links open in a new tab or the current one
+    #[serde(default)]
+    pub underline_easing: Easing, // Curve button underlines follow when moving on hover
+    #[serde(default = "Data::default_thumbnail_border")]
+    pub thumbnail_border: bool, // Whether project thumbnails get a 1px border in the palette's `border` color
+    #[serde(default)]
+    pub breakpoints: Breakpoints, // Window widths at which the layout switches to the medium and large tiers
+    #[serde(default)]
+    pub scrollbar: ScrollbarVisibility, // Whether the page's scroll bar hides while idle
+    #[serde(default)]
+    pub updated: Option<Date>, // When the content was last updated, shown in the footer when set
+    #[serde(default)]
+    pub about: String, // Bio shown under the socials, collapsed to a preview when long; hidden when empty
+    #[serde(default)]
+    pub status: Option<String>, // Live status shown as a pill under the hero, e.g. "Currently building: X"; hidden when unset
+    #[serde(default)]
+    pub analytics: Option<AnalyticsConfig>, // Where link and project clicks are reported; nothing is tracked when unset
+    #[serde(default = "PageSection::default_keys")]
+    pub sections: Vec<String>, // Keys of the page sections in display order, see [`Data::page_sections`]
     pub skills: Vec<Skill>,
     pub project_highlights: Vec<ProjectHighlight>,
+    #[serde(default)]
+    pub timeline: Vec<TimelineEntry>, // Work and education in display order; the section is hidden when empty
+    #[serde(skip)]
+    pub load_error: Option<String>, // Set when `data.toml` failed to parse and the empty fallback is used instead
 }
 
-#[derive(Serialize, Deserialize)]
+/// The order the page lists skills in, set from `skill_order` in `data.toml`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkillOrder {
+    #[default]
+    Listed, // As written in `data.toml`
+    Name, // Alphabetically, see [`Data::skills_sorted_by_name`]
+    Level, // Most proficient first, see [`Data::skills_sorted_by_level`]
+}
+
+/// A role or course on the experience timeline, see [`crate::elements::timeline`].
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct TimelineEntry {
+    pub title: String, // The role or degree, e.g. "Backend Developer"
+    pub org: String, // The company or school
+    pub period: String, // Free-form dates, e.g. "2022 – present"
+    #[serde(default)]
+    pub description: String, // Hidden when empty
+}
+
+/// A block of the page that `data.toml` can reorder or leave out through `sections`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PageSection {
+    Hero,
+    About,
+    Skills,
+    Projects,
+    Timeline,
+    Contact,
+}
+
+impl PageSection {
+    /// The order the page uses when `data.toml` doesn't list its sections.
+    pub const DEFAULT_ORDER: [Self; 6] = [Self::Hero, Self::About, Self::Skills, Self::Projects, Self::Timeline, Self::Contact];
+
+    /// The name of the section in `data.toml`.
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Hero => "hero",
+            Self::About => "about",
+            Self::Skills => "skills",
+            Self::Projects => "projects",
+            Self::Timeline => "timeline",
+            Self::Contact => "contact",
+        }
+    }
+
+    /// The section with the given `data.toml` name, if there is one.
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::DEFAULT_ORDER.into_iter().find(|section| section.key() == key)
+    }
+
+    fn default_keys() -> Vec<String> {
+        Self::DEFAULT_ORDER.iter().map(|section| section.key().to_owned()).collect()
+    }
+}
+
+/// Named color roles for one theme, so components never hard-code brand colors.
+/// Every role can be overridden from `data.toml` as an `[r, g, b]` array.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug)]
+pub struct Palette {
+    #[serde(with = "rgb")]
+    pub primary_bg: Color32, // Page, panel and deepest background
+    #[serde(with = "rgb")]
+    pub secondary_bg: Color32, // Resting fill for interactive widgets
+    #[serde(with = "rgb")]
+    pub surface: Color32, // Cards, groups and weak fills layered on the background
+    #[serde(with = "rgb")]
+    pub window_bg: Color32, // Windows and popups
+    #[serde(with = "rgb")]
+    pub text: Color32,
+    #[serde(with = "rgb")]
+    pub border: Color32, // Widget and window strokes
+    #[serde(with = "rgb")]
+    pub accent: Color32, // Brand color used by the background gradient
+    #[serde(with = "rgb")]
+    pub link: Color32,
+    #[serde(with = "rgb")]
+    pub selection: Color32,
+}
+
+/// The palette for each theme, see [`Palette`].
+#[derive(Clone, Copy, Serialize, Deserialize, Debug)]
+#[serde(default)]
+pub struct Palettes {
+    pub dark: Palette,
+    pub light: Palette,
+}
+
+/// A project as listed in `data.toml`. The same type is rendered directly: its `thumbnail_path` is resolved into
+/// [`ProjectHighlight::thumbnail`] lazily, the first frames the card is drawn, see [`ProjectHighlight::get_set_thumbnail`].
+#[derive(Clone, Serialize, Deserialize)]
 pub struct ProjectHighlight {
     pub slug: String,
     pub title: String,
     pub description: String,
-    pub tags: Vec<Skill>,
+    pub tags: Vec<Tag>,
     thumbnail_path: String,
     pub external_link: String,
+    #[serde(default)]
+    pub live_url: Option<String>, // Live demo, linked from the card when set
+    #[serde(default)]
+    pub source_url: Option<String>, // Source repository, linked from the card when set
+    #[serde(default)]
+    pub featured: bool, // Pinned above the other projects with a full-width, outlined card
     pub highlight_imgs: Vec<String>,
     #[serde(skip)]
     pub thumbnail: Option<SizedTexture>, // Store the thumbnail as a SizedTexture directly
+    #[serde(skip)]
+    thumbnail_error: Option<String>, // Set once loading the thumbnail failed, so we stop polling
+}
+
+/// Loading state of a project thumbnail, see [`ProjectHighlight::get_set_thumbnail`].
+#[derive(Clone, Copy, Debug)]
+pub enum ThumbnailState {
+    Ready(SizedTexture),
+    Loading,
+    Failed,
+}
+
+/// Where analytics events are sent, see [`crate::analytics::sink_from_config`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AnalyticsConfig {
+    Endpoint { url: String }, // POSTs every event as JSON
+    Gtag, // Calls the page's `gtag` function, which the page has to load itself
+}
+
+/// A calendar date, written as an ISO `YYYY-MM-DD` string in `data.toml`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Date {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
 }
 
 const RAW_DATA: &str = include_str!("../data.toml");
+static PARSED_DATA: OnceLock<Data> = OnceLock::new(); // `RAW_DATA` is parsed once, later constructions clone it
 
 impl Skill {
+    /// The range a skill's `level` must fall in.
+    pub const LEVELS: std::ops::RangeInclusive<u8> = 1..=5;
+
     pub fn color(&self) -> Color32 {
         Color32::from_rgb(self.rgb[0], self.rgb[1], self.rgb[2])
     }
@@ -39,13 +283,228 @@ impl Skill {
     pub fn text_color(&self) -> Color32 {
         Color32::from_rgb(self.text_rgb[0], self.text_rgb[1], self.text_rgb[2])
     }
+
+    /// Resolves the icon path (if any) against the given root url.
+    pub fn icon_uri(&self, root_url: &str) -> Option<String> {
+        self.icon.as_ref().map(|icon| resolve_url(root_url, icon))
+    }
+}
+
+fn is_absolute_url(path: &str) -> bool {
+    path.starts_with("http://") || path.starts_with("https://")
+}
+
+/// Joins `path` onto `root_url` with exactly one `/` between them, leaving absolute `http(s)` urls unchanged.
+pub fn resolve_url(root_url: &str, path: &str) -> String {
+    if is_absolute_url(path) {
+        path.to_owned()
+    } else {
+        format!("{}/{}", root_url.trim_end_matches('/'), path.trim_start_matches('/'))
+    }
+}
+
+impl Tag {
+    /// Background color for tags without a matching skill, readable on either theme.
+    pub const NEUTRAL_COLOR: Color32 = Color32::from_gray(96);
+
+    pub fn color(&self) -> Color32 {
+        self.colors.map_or(Self::NEUTRAL_COLOR, |(color, _)| color)
+    }
+
+    /// The matching skill's text color, `None` for tags without a matching skill.
+    pub fn text_color(&self) -> Option<Color32> {
+        self.colors.map(|(_, text_color)| text_color)
+    }
+
+    /// Resolves the icon path (if any) against the given root url.
+    pub fn icon_uri(&self, root_url: &str) -> Option<String> {
+        self.icon.as_ref().map(|icon| resolve_url(root_url, icon))
+    }
+}
+
+impl Palette {
+    pub fn dark() -> Self {
+        Self {
+            primary_bg: Color32::from_rgb(16, 17, 18),
+            secondary_bg: Color32::from_rgb(19, 41, 61),
+            surface: Color32::from_gray(32),
+            window_bg: Color32::from_rgb(16, 17, 18),
+            text: Color32::from_rgb(240, 235, 216),
+            border: Color32::from_gray(60),
+            accent: Color32::from_rgb(95, 15, 64),
+            link: Color32::from_rgb(240, 235, 255),
+            selection: Color32::from_rgb(75, 75, 75),
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            primary_bg: Color32::from_rgb(202, 233, 255),
+            secondary_bg: Color32::from_rgb(27, 73, 101),
+            surface: Color32::from_rgb(190, 233, 232),
+            window_bg: Color32::from_rgb(122, 156, 198),
+            text: Color32::from_rgb(33, 34, 39),
+            border: Color32::from_rgb(99, 112, 116),
+            accent: Color32::from_rgb(95, 15, 64),
+            link: Color32::from_rgb(33, 34, 255),
+            selection: Color32::from_rgb(99, 112, 116),
+        }
+    }
+
+    /// Pure black and white for the high-contrast theme, with yellow links and a saturated blue selection.
+    /// Not configurable from `data.toml`, so brand colors can't weaken it.
+    pub fn high_contrast() -> Self {
+        Self {
+            primary_bg: Color32::BLACK,
+            secondary_bg: Color32::BLACK,
+            surface: Color32::BLACK,
+            window_bg: Color32::BLACK,
+            text: Color32::WHITE,
+            border: Color32::WHITE,
+            accent: Color32::BLACK, // Flattens the background and hero gradients
+            link: Color32::from_rgb(255, 255, 0),
+            selection: Color32::from_rgb(0, 80, 255),
+        }
+    }
+}
+
+impl Default for Palettes {
+    fn default() -> Self {
+        Self {
+            dark: Palette::dark(),
+            light: Palette::light(),
+        }
+    }
+}
+
+impl Palettes {
+    pub fn for_theme(&self, theme: egui::Theme) -> &Palette {
+        match theme {
+            egui::Theme::Dark => &self.dark,
+            egui::Theme::Light => &self.light,
+        }
+    }
+}
+
+/// (De)serializes an opaque [`Color32`] as an `[r, g, b]` array, like the skill colors in `data.toml`.
+mod rgb {
+    use super::{Color32, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(color: &Color32, serializer: S) -> Result<S::Ok, S::Error> {
+        [color.r(), color.g(), color.b()].serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color32, D::Error> {
+        let [r, g, b] = super::RgbInput::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)?;
+        Ok(Color32::from_rgb(r, g, b))
+    }
+}
+
+impl TryFrom<String> for Date {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let invalid = || format!("expected an ISO date like 2025-01-31, got {value:?}");
+        let mut parts = value.splitn(3, '-');
+        let mut next = |len: usize| parts.next().filter(|part| part.len() == len && part.bytes().all(|b| b.is_ascii_digit()));
+        let (Some(year), Some(month), Some(day)) = (next(4), next(2), next(2)) else {
+            return Err(invalid());
+        };
+        let parse_error = |err: std::num::ParseIntError| format!("{} ({err})", invalid());
+        let date = Self {
+            year: year.parse().map_err(parse_error)?,
+            month: month.parse().map_err(parse_error)?,
+            day: day.parse().map_err(parse_error)?,
+        };
+        if !(1..=12).contains(&date.month) || date.day == 0 || date.day > date.days_in_month() {
+            return Err(invalid());
+        }
+        Ok(date)
+    }
+}
+
+impl From<Date> for String {
+    fn from(date: Date) -> Self {
+        date.to_string()
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl Date {
+    fn days_in_month(&self) -> u8 {
+        match self.month {
+            2 if self.year % 4 == 0 && (self.year % 100 != 0 || self.year % 400 == 0) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Days since 1970-01-01, see <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+    fn days_since_epoch(&self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = self.month as i64;
+        let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Describes the date relative to today, e.g. "3 months ago".
+    /// Returns `None` when the current date isn't available.
+    pub fn relative(&self) -> Option<String> {
+        days_since_epoch_now().map(|today| describe_days_ago(today - self.days_since_epoch()))
+    }
+}
+
+/// The current day, counted like [`Date::days_since_epoch`].
+fn days_since_epoch_now() -> Option<i64> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        // `SystemTime` isn't available on the web, the page's clock is
+        web_sys::window()
+            .and_then(|window| window.performance())
+            .map(|performance| ((performance.time_origin() + performance.now()) / 86_400_000.0).floor() as i64)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .ok()
+            .map(|elapsed| (elapsed.as_secs() / 86_400) as i64)
+    }
+}
+
+/// Phrases a number of days in the past, rounding down to the largest whole unit.
+fn describe_days_ago(days: i64) -> String {
+    let (count, unit) = match days {
+        ..=0 => return "today".to_owned(), // Dates in the future are treated as today
+        1 => return "yesterday".to_owned(),
+        2..30 => (days, "day"),
+        30..365 => (days / 30, "month"),
+        _ => (days / 365, "year"),
+    };
+    format!("{count} {unit}{} ago", if count == 1 { "" } else { "s" })
 }
 
 impl Default for Data {
     fn default() -> Self {
-        let _self: Data = toml::from_str(RAW_DATA).expect("Failed to parse data.toml");
-        log::debug!("Data loaded: {:?}", _self);
-        _self
+        PARSED_DATA.get_or_init(|| match Self::try_new() {
+            Ok(_self) => {
+                log::debug!("Data loaded: {_self:?}");
+                _self
+            },
+            Err(e) => {
+                log::error!("Failed to parse data.toml: {e}");
+                Self::empty(Some(e.message().to_owned()))
+            }
+        }).clone()
     }
 }
 
@@ -54,10 +513,126 @@ impl Data {
         Data::default()
     }
 
+    /// Parses the embedded `data.toml`, without the fallback [`Data::new`] uses when it is malformed.
+    pub fn try_new() -> Result<Self, toml::de::Error> {
+        Self::from_toml(RAW_DATA)
+    }
+
+    /// Parses data in the `data.toml` format, e.g. a copy fetched at runtime.
+    pub fn from_toml(text: &str) -> Result<Self, toml::de::Error> {
+        let mut data: Self = toml::from_str(text)?;
+        data.resolve_tag_colors();
+        for key in data.sections.iter().filter(|key| PageSection::from_key(key).is_none()) {
+            log::warn!("Ignoring unknown section `{key}` in data.toml");
+        }
+        Ok(data)
+    }
+
+    /// The known sections listed in `sections`, in display order and each only once.
+    /// Unknown keys are skipped, and logged when the data loads.
+    pub fn page_sections(&self) -> Vec<PageSection> {
+        let mut sections = Vec::new();
+        for section in self.sections.iter().filter_map(|key| PageSection::from_key(key)) {
+            if !sections.contains(&section) {
+                sections.push(section);
+            }
+        }
+        sections
+    }
+
+    /// The background and text color of the skill with the given name (case-insensitive), if there is one.
+    pub fn skill_color(&self, name: &str) -> Option<(Color32, Color32)> {
+        self.skills.iter()
+            .find(|skill| skill.name.eq_ignore_ascii_case(name))
+            .map(|skill| (skill.color(), skill.text_color()))
+    }
+
+    /// Colors every project tag like the skill of the same name, so both sections share one visual language.
+    fn resolve_tag_colors(&mut self) {
+        let colors: Vec<_> = self.project_highlights.iter()
+            .flat_map(|project| &project.tags)
+            .map(|tag| self.skill_color(&tag.name))
+            .collect();
+        for (tag, colors) in self.project_highlights.iter_mut().flat_map(|project| project.tags.iter_mut()).zip(colors) {
+            tag.colors = colors;
+        }
+    }
+
+    /// Data with no skills or projects, using the built-in palettes and logo.
+    /// - `load_error`: Why the real data is unavailable, if it failed to load.
+    pub fn empty(load_error: Option<String>) -> Self {
+        Self {
+            contact_endpoint: None,
+            palette: Palettes::default(),
+            logo_path: None,
+            resume_path: None,
+            skill_order: SkillOrder::default(),
+            link_target: LinkTarget::default(),
+            underline_easing: Easing::default(),
+            thumbnail_border: Self::default_thumbnail_border(),
+            breakpoints: Breakpoints::default(),
+            scrollbar: ScrollbarVisibility::default(),
+            updated: None,
+            about: String::new(),
+            status: None,
+            analytics: None,
+            sections: PageSection::default_keys(),
+            skills: Vec::new(),
+            project_highlights: Vec::new(),
+            timeline: Vec::new(),
+            load_error,
+        }
+    }
+
+    fn default_thumbnail_border() -> bool {
+        true
+    }
+
     pub fn skills(&self) -> &[Skill] {
         &self.skills
     }
 
+    /// Returns the skills sorted alphabetically (case-insensitive) by name, leaving the raw order untouched.
+    /// Skills with equal names keep their original `data.toml` order.
+    pub fn skills_sorted_by_name(&self) -> Vec<&Skill> {
+        let mut skills: Vec<&Skill> = self.skills.iter().collect();
+        skills.sort_by_cached_key(|skill| skill.name.to_lowercase());
+        skills
+    }
+
+    /// Returns the skills sorted by `level`, most proficient first, leaving the raw order untouched.
+    /// Skills without a level come last. Skills with equal levels keep their original `data.toml` order.
+    pub fn skills_sorted_by_level(&self) -> Vec<&Skill> {
+        let mut skills: Vec<&Skill> = self.skills.iter().collect();
+        skills.sort_by_key(|skill| std::cmp::Reverse(skill.level));
+        skills
+    }
+
+    /// The skills in the order the page lists them, following [`Data::skill_order`].
+    pub fn listed_skills(&self) -> Vec<&Skill> {
+        match self.skill_order {
+            SkillOrder::Listed => self.skills().iter().collect(),
+            SkillOrder::Name => self.skills_sorted_by_name(),
+            SkillOrder::Level => self.skills_sorted_by_level(),
+        }
+    }
+
+    /// Resolves the résumé path (if any) against the given root url.
+    /// Absolute `http(s)` urls are returned unchanged.
+    pub fn resume_url(&self, root_url: &str) -> Option<String> {
+        self.resume_path.as_ref().map(|path| resolve_url(root_url, path))
+    }
+
+    /// The brand logo, resolved against the given root url.
+    /// Falls back to the croissant embedded in the binary when no logo is configured or there is no root url
+    /// to fetch assets from (e.g. native builds).
+    pub fn logo_source(&self, root_url: &str) -> ImageSource<'static> {
+        match &self.logo_path {
+            Some(path) if is_absolute_url(path) || !root_url.is_empty() => ImageSource::Uri(resolve_url(root_url, path).into()),
+            _ => include_image!("../assets/croissant.png"),
+        }
+    }
+
     pub fn project_highlights(&self) -> &[ProjectHighlight] {
         &self.project_highlights
     }
@@ -75,6 +650,9 @@ impl fmt::Debug for ProjectHighlight {
             .field("description", &self.description)
             .field("tags", &self.tags)
             .field("external_link", &self.external_link)
+            .field("live_url", &self.live_url)
+            .field("source_url", &self.source_url)
+            .field("featured", &self.featured)
             .field("thumbnail_path", &self.thumbnail_path)
             .finish()
     }
@@ -85,7 +663,7 @@ impl ProjectHighlight {
         slug: String,
         title: String,
         description: String,
-        tags: Vec<Skill>,
+        tags: Vec<Tag>,
         external_link: String,
         highlight_imgs: Vec<String>,
         thumbnail_path: String,
@@ -96,36 +674,239 @@ impl ProjectHighlight {
             description,
             tags,
             external_link,
+            live_url: None,
+            source_url: None,
+            featured: false,
             thumbnail: None,
+            thumbnail_error: None,
             highlight_imgs: highlight_imgs,
             thumbnail_path,
         }
     }
 
-    pub fn get_set_thumbnail(&mut self, root_url: &String, ctx: &egui::Context) -> Option<SizedTexture> {
+    pub fn thumbnail_path(&self) -> &str {
+        &self.thumbnail_path
+    }
+
+    /// Polls the thumbnail texture through egui's image loaders, caching it once ready.
+    /// The first call starts the load. A missing or undecodable image is remembered as failed, leaving `thumbnail`
+    /// unset so the card shows a broken-image placeholder instead, without polling again.
+    /// - `root_url`: The url `thumbnail_path` is relative to.
+    /// - `ctx`: The egui context whose loaders fetch and decode the image.
+    pub fn get_set_thumbnail(&mut self, root_url: &String, ctx: &egui::Context) -> ThumbnailState {
         if let Some(thumbnail) = self.thumbnail {
-            Some(thumbnail)
+            ThumbnailState::Ready(thumbnail)
+        } else if self.thumbnail_error.is_some() {
+            ThumbnailState::Failed
         } else {
-            let thumbnail_full_path = root_url.clone() + &self.thumbnail_path;
+            let thumbnail_full_path = resolve_url(root_url, &self.thumbnail_path);
             let poll_result = ctx.try_load_texture(&thumbnail_full_path, Default::default(), Default::default());
             match poll_result {
                 Ok(texture_poll) => {
                     match texture_poll {
                         TexturePoll::Ready { texture } => {
                             self.thumbnail = Some(texture);
-                            self.thumbnail
+                            ThumbnailState::Ready(texture)
                         },
                         TexturePoll::Pending { .. } => {
-                            None
+                            ThumbnailState::Loading
                         }
                     }
                 },
                 Err(e) => {
                     log::error!("Failed to load thumbnail: {}", e);
-                    None
+                    self.thumbnail_error = Some(e.to_string());
+                    ThumbnailState::Failed
                 }
             }
 
         }
     }
-}
\ No newline at end of file
+}
+
+/// A project titled "Project" with no images, for tests that only care about its slug and tags.
+/// - `slug`: The project's slug, which also names its link and thumbnail.
+/// - `tags`: The names of its tags, left uncolored.
+#[cfg(test)]
+pub fn project(slug: &str, tags: &[&str]) -> ProjectHighlight {
+    let tags = tags.iter().map(|&name| Tag { name: name.to_owned(), icon: None, colors: None }).collect();
+    ProjectHighlight::new(
+        slug.to_owned(),
+        "Project".to_owned(),
+        String::new(),
+        tags,
+        format!("https://example.com/{slug}"),
+        Vec::new(),
+        format!("/assets/{slug}.png"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_data_parses() {
+        let data = Data::try_new().expect("data.toml should match the schema");
+        assert!(!data.skills.is_empty(), "data.toml should list at least one skill");
+        assert!(data.load_error.is_none());
+        for project in &data.project_highlights {
+            assert!(!project.slug.is_empty() && !project.title.is_empty(), "{project:?} needs a slug and a title");
+            let duplicates = data.project_highlights.iter().filter(|other| other.slug == project.slug).count();
+            assert_eq!(duplicates, 1, "project slugs are used in urls and must be unique: {}", project.slug);
+        }
+    }
+
+    #[test]
+    fn optional_fields_default() {
+        let data = Data::from_toml(r#"
+            skills = [{ name = "rust", rgb = [211, 69, 22], text_rgb = [255, 255, 255] }]
+            project_highlights = []
+        "#).expect("minimal data");
+        assert_eq!(data.contact_endpoint, None);
+        assert_eq!(data.logo_path, None);
+        assert_eq!(data.resume_path, None);
+        assert_eq!(data.status, None);
+        assert_eq!(data.skills[0].icon, None);
+        assert!(data.timeline.is_empty());
+        assert_eq!(data.palette.dark.accent, Palette::dark().accent);
+        assert_eq!(data.palette.light.text, Palette::light().text);
+        assert_eq!(data.page_sections(), PageSection::DEFAULT_ORDER);
+    }
+
+    #[test]
+    fn sections_follow_data_order_and_skip_unknown_keys() {
+        let data = Data::from_toml(r#"
+            sections = ["projects", "hero", "blog", "contact", "projects"]
+            skills = []
+            project_highlights = []
+        "#).expect("sections data");
+        assert_eq!(data.page_sections(), [PageSection::Projects, PageSection::Hero, PageSection::Contact]);
+    }
+
+    #[test]
+    fn breakpoints_fall_back_to_the_defaults() {
+        let parse = |toml: &str| Data::from_toml(&format!("{toml}\nskills = []\nproject_highlights = []")).unwrap_or_else(|err| panic!("`{toml}` should parse: {err}")).breakpoints;
+        assert_eq!(parse(""), Breakpoints::default());
+        assert_eq!(parse("breakpoints = { medium = 600, large = 1200 }"), Breakpoints { medium: 600.0, large: 1200.0 });
+        assert_eq!(parse("breakpoints = { large = 1200 }"), Breakpoints { large: 1200.0, ..Breakpoints::default() });
+    }
+
+    #[test]
+    fn underline_easing_defaults_to_ease_out() {
+        let parse = |toml: &str| Data::from_toml(&format!("{toml}\nskills = []\nproject_highlights = []")).unwrap_or_else(|err| panic!("`{toml}` should parse: {err}")).underline_easing;
+        assert_eq!(parse(""), Easing::EaseOut);
+        assert_eq!(parse("underline_easing = \"linear\""), Easing::Linear);
+        assert_eq!(parse("underline_easing = \"ease_in_out\""), Easing::EaseInOut);
+    }
+
+    #[test]
+    fn skills_can_be_listed_by_name() {
+        let data = Data::from_toml(r#"
+            skill_order = "name"
+            skills = [
+                { name = "sql", rgb = [0, 0, 0], text_rgb = [255, 255, 255] },
+                { name = "Rust", rgb = [1, 0, 0], text_rgb = [255, 255, 255] },
+                { name = "rust", rgb = [2, 0, 0], text_rgb = [255, 255, 255] },
+                { name = "egui", rgb = [3, 0, 0], text_rgb = [255, 255, 255] },
+            ]
+            project_highlights = []
+        "#).expect("skills ordered by name");
+        let listed: Vec<(&str, u8)> = data.listed_skills().iter().map(|skill| (skill.name.as_str(), skill.rgb[0])).collect();
+        assert_eq!(listed, [("egui", 3), ("Rust", 1), ("rust", 2), ("sql", 0)], "case-insensitive, ties keep their order");
+        let raw: Vec<&str> = data.skills().iter().map(|skill| skill.name.as_str()).collect();
+        assert_eq!(raw, ["sql", "Rust", "rust", "egui"], "the raw order is untouched");
+    }
+
+    #[test]
+    fn skills_can_be_listed_by_level() {
+        let data = Data::from_toml(r#"
+            skill_order = "level"
+            skills = [
+                { name = "sql", rgb = [0, 0, 0], text_rgb = [255, 255, 255] },
+                { name = "css", rgb = [0, 0, 0], text_rgb = [255, 255, 255], level = 3 },
+                { name = "rust", rgb = [0, 0, 0], text_rgb = [255, 255, 255], level = 5 },
+                { name = "html", rgb = [0, 0, 0], text_rgb = [255, 255, 255], level = 3 },
+                { name = "egui", rgb = [0, 0, 0], text_rgb = [255, 255, 255] },
+            ]
+            project_highlights = []
+        "#).expect("skills ordered by level");
+        let listed: Vec<&str> = data.listed_skills().iter().map(|skill| skill.name.as_str()).collect();
+        assert_eq!(listed, ["rust", "css", "html", "sql", "egui"], "equal and missing levels keep their order, missing ones last");
+        let raw: Vec<&str> = data.skills().iter().map(|skill| skill.name.as_str()).collect();
+        assert_eq!(raw, ["sql", "css", "rust", "html", "egui"], "the raw order is untouched");
+    }
+
+    #[test]
+    fn timeline_entries_keep_their_order() {
+        let data = Data::from_toml(r#"
+            skills = []
+            project_highlights = []
+
+            [[timeline]]
+            title = "Backend Developer"
+            org = "Example Corp"
+            period = "2023 – present"
+            description = "Built the ingestion pipeline."
+
+            [[timeline]]
+            title = "B.Sc. Computer Science"
+            org = "Example University"
+            period = "2019 – 2023"
+        "#).expect("timeline data");
+        let titles: Vec<_> = data.timeline.iter().map(|entry| entry.title.as_str()).collect();
+        assert_eq!(titles, ["Backend Developer", "B.Sc. Computer Science"]);
+        assert_eq!(data.timeline[1].description, "", "descriptions are optional");
+    }
+
+    #[test]
+    fn updated_date_is_validated_and_described() {
+        let date = |text: &str| Date::try_from(text.to_owned());
+        assert_eq!(date("2024-02-29"), Ok(Date { year: 2024, month: 2, day: 29 }));
+        for invalid in ["2023-02-29", "2024-13-01", "2024-1-01", "24-01-01", "2024-01-01T00:00", "yesterday"] {
+            assert!(date(invalid).is_err(), "{invalid} should be rejected");
+        }
+        assert_eq!(date("1970-01-01").expect("the epoch").days_since_epoch(), 0);
+        assert_eq!(date("2000-03-01").expect("after a leap day").days_since_epoch(), 11_017);
+
+        assert_eq!(describe_days_ago(0), "today");
+        assert_eq!(describe_days_ago(1), "yesterday");
+        assert_eq!(describe_days_ago(45), "1 month ago");
+        assert_eq!(describe_days_ago(100), "3 months ago");
+        assert_eq!(describe_days_ago(800), "2 years ago");
+    }
+
+    #[test]
+    fn thumbnail_border_is_on_unless_turned_off() {
+        let parse = |toml: &str| Data::from_toml(&format!("{toml}\nskills = []\nproject_highlights = []")).expect(toml).thumbnail_border;
+        assert!(parse(""));
+        assert!(!parse("thumbnail_border = false"));
+        assert!(Data::empty(None).thumbnail_border);
+    }
+
+    #[test]
+    fn skill_colors_explain_what_is_wrong() {
+        let parse = |rgb: &str| Data::from_toml(&format!(r#"
+            skills = [{{ name = "rust", rgb = {rgb}, text_rgb = [255, 255, 255] }}]
+            project_highlights = []
+        "#));
+        assert_eq!(parse(r##""#d34516""##).expect("hex color").skills[0].rgb, [211, 69, 22], "hex colors should be accepted");
+        for (rgb, expected) in [
+            ("[300, 0, 0]", "between 0 and 255, got 300"),
+            ("[255, 0]", "expected 3 channels"),
+            (r#""red""#, "expected a hex color"),
+            (r##""#zz0000""##, "invalid digit"),
+        ] {
+            let err = parse(rgb).expect_err(rgb).to_string();
+            assert!(err.contains("skill `rust`: invalid `rgb`") && err.contains(expected), "{rgb}: {err}");
+        }
+    }
+
+    #[test]
+    fn data_round_trips_through_toml() {
+        let data = Data::try_new().expect("bundled data");
+        let serialized = toml::to_string(&data).expect("Data should serialize back to toml");
+        let reparsed = Data::from_toml(&serialized).expect("serialized data should parse again");
+        assert_eq!(format!("{reparsed:?}"), format!("{data:?}"));
+    }
+}
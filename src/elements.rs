@@ -1,10 +1,16 @@
 use std::vec;
 
 use egui::{emath, epaint, frame::Prepared, lerp, modal, pos2, text::Fonts, text_selection::visuals, vec2, Atom, AtomKind, AtomLayout, AtomLayoutResponse, Button, Color32, CornerRadius, FontId, Frame, Galley, Image, IntoAtoms, Margin, Mesh, Painter, Pos2, Rect, Response, Rgba, Sense, Stroke, TextWrapMode, TextureHandle, Ui, UiBuilder, Vec2, Widget, WidgetInfo, WidgetText, WidgetType};
-use web_sys::{window, Url};
-use std::collections::HashMap;
+use web_sys::{wasm_bindgen::JsCast as _, HtmlAnchorElement};
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex};
 
-use crate::data::{ProjectHighlight, Skill};
+use crate::analytics::track;
+use crate::clock::{animate_bool, animate_value, now};
+use crate::color::best_text_color;
+use crate::data::{resolve_url, ProjectHighlight, Skill, Tag, ThumbnailState, TimelineEntry};
+use crate::app::ScreenSize;
+use crate::route::Route;
 
 /// A button widget with an optional underline. Copies main structure from original `egui::Button`
 #[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
@@ -21,6 +27,52 @@ pub struct ButtonWithUnderline<'a> {
     selected: bool,
     inset: Vec2,
     hover_inset: Vec2,
+    enabled: bool,
+    rtl: bool,
+    badge: Option<String>,
+    badge_color: Option<Color32>, // Defaults to the selection color
+    accessible_label: Option<String>, // Announced instead of the visible text, and shown as a tooltip on icon-only buttons
+    underline_fit: UnderlineFit,
+    underline_on_hover: bool, // Fades the underline in on hover or focus instead of always showing it
+}
+
+/// How much of a [`ButtonWithUnderline`] the underline spans, before its insets are applied.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UnderlineFit {
+    /// The whole button, less its padding.
+    #[default]
+    Full,
+    /// Only the laid-out contents (text, images, badge), for a "link with hover underline" look
+    /// on framed or stretched buttons.
+    Content,
+}
+
+/// Shape of an animation over time, applied to a linear `0.0..=1.0` progress factor before lerping.
+/// The underlines of [`ButtonWithUnderline`] follow the one set with [`set_underline_easing`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Easing {
+    Linear,
+    /// Starts fast and settles gently, the most natural for UI responding to input.
+    #[default]
+    EaseOut,
+    EaseInOut,
+}
+
+impl Easing {
+    /// Maps the linear progress `t` (clamped to `0.0..=1.0`) onto the curve.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Self::EaseInOut => if t < 0.5 {
+                4.0 * t.powi(3)
+            } else {
+                1.0 - (2.0 - 2.0 * t).powi(3) / 2.0
+            },
+        }
+    }
 }
 
 impl<'a> ButtonWithUnderline<'a> {
@@ -38,9 +90,24 @@ impl<'a> ButtonWithUnderline<'a> {
             underline_color: None,
             inset: Vec2::ZERO,
             hover_inset: Vec2::splat(-2.0),
+            enabled: true,
+            rtl: false,
+            badge: None,
+            badge_color: None,
+            accessible_label: None,
+            underline_fit: UnderlineFit::Full,
+            underline_on_hover: false,
         }
     }
 
+    /// Show a button containing only an icon, sized like a line of button text.
+    ///
+    /// Give it an [`Self::accessible_label`] so screen readers and the tooltip can describe it.
+    /// Takes an image source, or an [`Image`] already set up e.g. with a [`Image::tint`].
+    pub fn icon_button(image: impl Into<Image<'a>>) -> Self {
+        Self::new(image.into().fit_to_exact_size(Vec2::splat(20.0)))
+    }
+
     /// Show a selectable button.
     ///
     /// Equivalent to:
@@ -183,6 +250,53 @@ impl<'a> ButtonWithUnderline<'a> {
         self
     }
 
+    /// If `false`, the button ignores clicks and hovers, is drawn with the non-interactive visuals
+    /// and a dimmed underline. This is independent of [`Ui::is_enabled`], both must be `true`.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// If `true`, lay the contents out right-to-left: atoms (including [`Self::right_text`] and
+    /// [`Self::shortcut_text`]) are mirrored and the underline insets are applied from the right.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn rtl(mut self, rtl: bool) -> Self {
+        self.rtl = rtl;
+        self
+    }
+
+    /// Show a small rounded pill after the contents, e.g. a count of items. An empty string shows nothing.
+    ///
+    /// The badge is laid out with the other atoms, so the button's size and underline include it.
+    #[inline]
+    pub fn badge(mut self, badge: impl Into<String>) -> Self {
+        self.badge = Some(badge.into());
+        self
+    }
+
+    /// Set the fill of the [`Self::badge`], e.g. to the palette's accent.
+    ///
+    /// Default: the selection color.
+    #[inline]
+    pub fn badge_color(mut self, badge_color: impl Into<Color32>) -> Self {
+        self.badge_color = Some(badge_color.into());
+        self
+    }
+
+    /// Set the label announced by screen readers, independent of the visible contents.
+    ///
+    /// Buttons without any visible text (e.g. [`Self::icon_button`]) also show it as a tooltip.
+    #[inline]
+    pub fn accessible_label(mut self, accessible_label: impl Into<String>) -> Self {
+        self.accessible_label = Some(accessible_label.into());
+        self
+    }
+
     /// Set the color of the underline.
     #[inline]
     pub fn underline_color(mut self, underline_color: impl Into<Color32>) -> Self {
@@ -190,6 +304,15 @@ impl<'a> ButtonWithUnderline<'a> {
         self
     }
 
+    /// Set how much of the button the underline spans, see [`UnderlineFit`].
+    ///
+    /// Default: [`UnderlineFit::Full`].
+    #[inline]
+    pub fn underline_fit(mut self, underline_fit: UnderlineFit) -> Self {
+        self.underline_fit = underline_fit;
+        self
+    }
+
     /// Set the inset of the button.
     #[inline]
     pub fn inset(mut self, inset: impl Into<Vec2>) -> Self {
@@ -208,6 +331,14 @@ impl<'a> ButtonWithUnderline<'a> {
         self
     }
 
+    /// If `true`, the underline is hidden at rest and fades in while the button is hovered or has keyboard focus,
+    /// following the easing set with [`set_underline_easing`]. Off by default.
+    #[inline]
+    pub fn underline_on_hover(mut self, underline_on_hover: bool) -> Self {
+        self.underline_on_hover = underline_on_hover;
+        self
+    }
+
     /// Show the button and return a [`AtomLayoutResponse`] for painting custom contents.
     pub fn atom_ui(self, ui: &mut Ui) -> AtomLayoutResponse {
         let ButtonWithUnderline {
@@ -223,9 +354,38 @@ impl<'a> ButtonWithUnderline<'a> {
             color,
             inset,
             hover_inset,
+            enabled,
+            rtl,
+            badge,
+            badge_color,
+            accessible_label,
+            underline_fit,
+            underline_on_hover,
         } = self;
 
-        let text = layout.text().map(String::from);
+        let visible_text = layout.text().map(String::from);
+        let tooltip = accessible_label.clone().filter(|_| visible_text.is_none());
+        let text = accessible_label.or(visible_text);
+        let enabled = enabled && ui.is_enabled();
+        // Reserve room for the badge as a custom atom, painted once the layout has placed it
+        let badge = badge.filter(|badge| !badge.is_empty()).map(|badge| {
+            let fill = badge_color.unwrap_or(ui.visuals().selection.bg_fill);
+            let text_color = best_text_color(fill);
+            let font_id = egui::TextStyle::Small.resolve(ui.style());
+            let galley = ui.fonts(|fonts| fonts.layout_no_wrap(badge, font_id, text_color));
+            let size = galley.size() + vec2(8.0, 2.0);
+            let id = ui.next_auto_id().with("badge");
+            layout.push_right(Atom::custom(id, vec2(size.x.max(size.y), size.y)));
+            (id, galley, fill)
+        });
+        if rtl {
+            // Mirror the atom order, so anything pushed to the right ends up on the left
+            layout.reverse();
+        }
+        if !enabled {
+            // Still sense hovers so tooltips keep working, but never clicks or focus
+            layout = layout.sense(Sense::hover());
+        }
 
         let has_frame_margin = frame.unwrap_or_else(|| ui.visuals().button_frame);
 
@@ -237,14 +397,20 @@ impl<'a> ButtonWithUnderline<'a> {
 
         let mut prepared = layout
             .frame(Frame::new().inner_margin(button_padding))
-            .min_size(min_size)
+            .min_size(min_size.max(min_touch_target(ui.ctx())))
             .allocate(ui);
 
-        let focus = prepared.response.hovered() || prepared.response.is_pointer_button_down_on() || prepared.response.has_focus();
+        // Keyboard focus highlights like a hover, focus gained by clicking doesn't linger after the pointer leaves
+        let focus = enabled && (prepared.response.hovered() || prepared.response.is_pointer_button_down_on() || focus_visible(ui, &prepared.response));
 
         let mut inner_margin;
-        let response = if ui.is_rect_visible(prepared.response.rect) {
-            let visuals = ui.style().interact_selectable(&prepared.response, selected);
+        let mut content_span = None;
+        let mut response = if ui.is_rect_visible(prepared.response.rect) {
+            let visuals = if enabled {
+                ui.style().interact_selectable(&prepared.response, selected)
+            } else {
+                *ui.visuals().noninteractive()
+            };
 
             let visible_frame = if frame_when_inactive {
                 has_frame_margin
@@ -253,7 +419,11 @@ impl<'a> ButtonWithUnderline<'a> {
                     && focus
             };
 
-            prepared.fallback_text_color = visuals.text_color();
+            prepared.fallback_text_color = if enabled {
+                visuals.text_color()
+            } else {
+                visuals.text_color().gamma_multiply(0.5)
+            };
 
             if visible_frame {
                 let stroke = stroke.unwrap_or(visuals.bg_stroke);
@@ -269,59 +439,125 @@ impl<'a> ButtonWithUnderline<'a> {
                     .corner_radius(corner_radius.unwrap_or(visuals.corner_radius));
             };
             inner_margin = prepared.frame.inner_margin.clone();
+            content_span = content_x_range(ui, &prepared);
             prepared.paint(ui)
         } else {
             inner_margin = Margin::default();
             AtomLayoutResponse::empty(prepared.response)
         };
-        
-        paint_underline(ui, &response.response, inner_margin, underline_color, if focus {
-            hover_inset
+
+        if let Some((id, galley, fill)) = badge {
+            if let Some(rect) = response.rect(id) {
+                let corner_radius = CornerRadius::same((rect.height() / 2.0) as u8);
+                ui.painter().add(Frame::new().fill(if enabled { fill } else { fill.gamma_multiply(0.5) }).corner_radius(corner_radius).paint(rect));
+                ui.painter().galley(rect.center() - galley.size() / 2.0, galley, Color32::PLACEHOLDER);
+            }
+        }
+
+        let underline_color = if enabled {
+            underline_color
+        } else {
+            Some(underline_color.unwrap_or_else(|| ui.visuals().text_color()).gamma_multiply(0.4))
+        };
+        // Hug the contents by underlining a rect that only spans them, without margins
+        let (underline_rect, underline_margins) = match (underline_fit, content_span) {
+            (UnderlineFit::Content, Some(span)) => (Rect::from_x_y_ranges(span, response.response.rect.y_range()), Margin::ZERO),
+            _ => (response.response.rect, inner_margin),
+        };
+        let hover_t = animate_bool(ui.ctx(), response.response.id.with("underline_inset"), focus, ui.style().animation_time);
+        let hover_t = underline_easing(ui.ctx()).apply(hover_t);
+        let underline_inset = lerp(inset..=hover_inset, hover_t);
+        let underline_color = if underline_on_hover {
+            Some(underline_color.unwrap_or_else(|| ui.visuals().text_color()).gamma_multiply(hover_t))
         } else {
-            inset
-        });
+            underline_color
+        };
+        paint_underline(ui, underline_rect, underline_margins, underline_color, underline_inset, rtl);
+        paint_focus_ring(ui, &response.response);
 
         response.response.widget_info(|| {
             if let Some(text) = &text {
-                WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), text)
+                WidgetInfo::labeled(WidgetType::Button, enabled, text)
             } else {
-                WidgetInfo::new(WidgetType::Button)
+                let mut info = WidgetInfo::new(WidgetType::Button);
+                info.enabled = enabled;
+                info
             }
         });
+        if let Some(tooltip) = tooltip {
+            response.response = response.response.on_hover_text(tooltip);
+        }
 
         response
     }
 }
 
+/// The horizontal extent of the laid-out atoms, ignoring space taken up by [`Atom::grow`] atoms.
+/// Mirrors the placement in [`egui::AllocatedAtomLayout::paint`], which doesn't report text or image rects.
+/// Returns `None` when there is nothing but grow atoms.
+fn content_x_range(ui: &Ui, prepared: &egui::AllocatedAtomLayout<'_>) -> Option<egui::Rangef> {
+    use emath::GuiRounding as _;
+    // `ButtonWithUnderline` keeps the default gap and alignment of its layout
+    let gap = ui.spacing().icon_spacing;
+    let align2 = egui::Align2([ui.layout().horizontal_align(), ui.layout().vertical_align()]);
+    let atoms = &prepared.sized_atoms;
+    let inner_rect = prepared.response.rect - prepared.frame.total_margin();
+    let desired_width = atoms.iter().map(|atom| atom.size.x).sum::<f32>() + gap * atoms.len().saturating_sub(1) as f32;
+    let grow_count = atoms.iter().filter(|atom| atom.is_grow()).count();
+    let grow_width = if grow_count > 0 {
+        ((inner_rect.width() - desired_width).max(0.0) / grow_count as f32).floor_ui()
+    } else {
+        0.0
+    };
+    let content_width = if grow_count > 0 { inner_rect.width() } else { desired_width };
+    let mut cursor = align2.align_size_within_rect(vec2(content_width, 0.0), inner_rect).left();
+
+    let mut span: Option<egui::Rangef> = None;
+    for atom in atoms {
+        if atom.is_grow() {
+            cursor += atom.size.x + grow_width + gap;
+            continue;
+        }
+        let slot = egui::Rangef::new(cursor, cursor + atom.size.x);
+        span = Some(span.map_or(slot, |span| egui::Rangef::new(span.min, slot.max)));
+        cursor = slot.max + gap;
+    }
+    span
+}
+
 /// Helper function to paint the underline for a button with an optional color.
 /// - `ui`: The UI context to draw on.
-/// - `response`: The response of the button.
+/// - `rect`: The rect to underline, usually the button's.
 /// - `margins`: The margins to apply.
 /// - `underline_color`: The color of the underline.
+/// - `inset`: The leading (`x`) and trailing (`y`) inset of the underline.
+/// - `rtl`: Whether the leading edge is on the right.
 fn paint_underline(
     ui: &mut Ui,
-    response: &Response,
+    rect: Rect,
     margins: Margin,
     underline_color: Option<Color32>,
     inset: Vec2,
+    rtl: bool,
 ) {
-    if let Some(underline_color) = underline_color {
-        let rect = response.rect;
-        let stroke = Stroke::new(1.0, underline_color);
-        ui.painter().line_segment(
-            [rect.left_bottom() + Vec2::new((margins.left as f32) + inset.x, 0.0), rect.right_bottom() + Vec2::new(-(margins.right as f32 + inset.y), 0.0)],
-            stroke,
-        );
+    let color = underline_color.unwrap_or_else(|| ui.visuals().text_color());
+    // As thick as the theme's text strokes, so the high-contrast theme's underlines stand out too
+    let stroke = Stroke::new(ui.visuals().widgets.noninteractive.fg_stroke.width.max(1.0), color);
+    ui.painter().line_segment(underline_points(rect, margins, inset, rtl), stroke);
+}
+
+/// Computes the start and end of an underline along the bottom of `rect`.
+/// `inset.x` is applied on the leading edge and `inset.y` on the trailing edge, mirrored when `rtl` is set.
+fn underline_points(rect: Rect, margins: Margin, inset: Vec2, rtl: bool) -> [Pos2; 2] {
+    let (leading, trailing) = if rtl {
+        (inset.y, inset.x)
     } else {
-        let visuals = ui.visuals();
-        let color = visuals.text_color();
-        let rect = response.rect;
-        let stroke = Stroke::new(1.0, color);
-        ui.painter().line_segment(
-            [rect.left_bottom() + Vec2::new((margins.left as f32) + inset.x, 0.0), rect.right_bottom() + Vec2::new(-(margins.right as f32 + inset.y), 0.0)],
-            stroke,
-        );
-    }
+        (inset.x, inset.y)
+    };
+    [
+        rect.left_bottom() + Vec2::new(margins.left as f32 + leading, 0.0),
+        rect.right_bottom() - Vec2::new(margins.right as f32 + trailing, 0.0),
+    ]
 }
 
 impl Widget for ButtonWithUnderline<'_> {
@@ -330,89 +566,496 @@ impl Widget for ButtonWithUnderline<'_> {
     }
 }
 
+/// Look and behavior of a [`card`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CardStyle {
+    pub fill: Color32,
+    pub hover_fill: Option<Color32>, // Swapped in for `fill` while hovered or keyboard focused
+    pub stroke: Stroke,
+    pub corner_radius: CornerRadius,
+    pub inner_margin: Margin, // Between the stroke and the contents
+    pub outer_margin: Margin, // Around the stroke, included in the card's response
+    pub shadow: egui::Shadow,
+    pub sense: Sense, // `Sense::click()` makes the whole card a button, with a pointing hand on hover
+    pub min_size: Vec2, // Smallest size of the card inside its outer margin
+}
+
+impl Default for CardStyle {
+    fn default() -> Self {
+        Self {
+            fill: Color32::TRANSPARENT,
+            hover_fill: None,
+            stroke: Stroke::NONE,
+            corner_radius: CornerRadius::ZERO,
+            inner_margin: Margin::ZERO,
+            outer_margin: Margin::ZERO,
+            shadow: egui::Shadow::NONE,
+            sense: Sense::hover(),
+            min_size: Vec2::ZERO,
+        }
+    }
+}
+
+impl CardStyle {
+    /// An outlined card like [`Frame::group`], following the theme's non-interactive widgets.
+    pub fn group(style: &egui::Style) -> Self {
+        Self {
+            stroke: style.visuals.widgets.noninteractive.bg_stroke,
+            corner_radius: style.visuals.widgets.noninteractive.corner_radius,
+            inner_margin: Margin::same(6),
+            ..Default::default()
+        }
+    }
+}
+
+/// Mutates the given ui to add a framed card around the given contents.
+/// The card senses input as a whole, so the contents shouldn't sense clicks of their own where the card does.
+/// - `ui`: The UI context to draw on.
+/// - `style`: The card's frame, hover fill and sense.
+/// - `add_contents`: Adds the contents of the card.
+pub fn card(ui: &mut Ui, style: CardStyle, add_contents: impl FnOnce(&mut Ui)) -> Response {
+    let mut prepared = Frame::new()
+        .fill(style.fill)
+        .stroke(style.stroke)
+        .corner_radius(style.corner_radius)
+        .inner_margin(style.inner_margin)
+        .outer_margin(style.outer_margin)
+        .shadow(style.shadow)
+        .begin(ui);
+    let chrome = style.inner_margin.sum() + Vec2::splat(2.0 * style.stroke.width);
+    prepared.content_ui.set_min_size((style.min_size - chrome).max(Vec2::ZERO));
+    add_contents(&mut prepared.content_ui);
+
+    let response = ui.allocate_rect(prepared.frame.outer_rect(prepared.content_ui.min_rect()), style.sense);
+    if let Some(hover_fill) = style.hover_fill {
+        if response.hovered() || focus_visible(ui, &response) {
+            prepared.frame.fill = hover_fill;
+        }
+    }
+    prepared.paint(ui);
+    if style.sense.senses_click() && response.hovered() {
+        ui.output_mut(|o| o.cursor_icon = egui::CursorIcon::PointingHand);
+    }
+    response
+}
+
+/// Mutates the given ui to display a small card containing a skill by name, sized to its content.
+/// - `ui`: The UI context to draw on.
+/// - `skill`: The name of the skill to display.
+/// - `color`: The background color of the skill card.
+/// - `text_color`: The text color of the skill name.
+/// - `icon`: An optional path to an icon to display before the skill name.
+/// - `font_size`: The font size of the skill name.
+pub fn skill_frameplate(ui: &mut Ui, skill: &str, color: Color32, text_color: Color32, icon: &Option<String>, font_size: f32) -> () {
+    skill_frameplate_sized(ui, skill, color, text_color, icon, font_size, &SkillChipStyle::default());
+}
+
+/// Size, spacing and shape of the cards drawn by [`skill_frameplate_sized`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SkillChipStyle {
+    pub height: Option<f32>, // Fixed outer height with the contents centered vertically, sized to the content when `None`
+    pub inner_margin: Margin, // Between the card's stroke and its contents
+    pub corner_radius: Option<CornerRadius>, // Follows the theme's non-interactive widgets when `None`
+    pub spacing: f32, // Between the icon and the name
+}
+
+impl Default for SkillChipStyle {
+    fn default() -> Self {
+        Self {
+            height: None,
+            inner_margin: Margin::same(2),
+            corner_radius: None,
+            spacing: 8.0,
+        }
+    }
+}
+
+/// Mutates the given ui to display a small card containing a skill by name, with black or white text,
+/// whichever contrasts more with `color`. Use [`skill_frameplate`] to keep a deliberately chosen text color.
+/// - `ui`: The UI context to draw on.
+/// - `skill`: The name of the skill to display.
+/// - `color`: The background color of the skill card.
+/// - `icon`: An optional path to an icon to display before the skill name.
+/// - `font_size`: The font size of the skill name.
+pub fn skill_frameplate_auto_contrast(ui: &mut Ui, skill: &str, color: Color32, icon: &Option<String>, font_size: f32) {
+    skill_frameplate_sized(ui, skill, color, best_text_color(color), icon, font_size, &SkillChipStyle::default());
+}
+
+/// Mutates the given ui to display a tag as a chip, colored like the skill of the same name.
+/// Tags without a matching skill are neutral, with black or white text, whichever contrasts more.
+/// - `ui`: The UI context to draw on.
+/// - `tag`: The tag to show.
+/// - `root_url`: The root url used to resolve the tag's icon.
+/// - `font_size`: The font size of the tag name.
+fn tag_chip(ui: &mut Ui, tag: &Tag, root_url: &str, font_size: f32) {
+    let icon = tag.icon_uri(root_url);
+    match tag.text_color() {
+        Some(text_color) => skill_frameplate(ui, &tag.name, tag.color(), text_color, &icon, font_size),
+        None => skill_frameplate_auto_contrast(ui, &tag.name, tag.color(), &icon, font_size),
+    }
+}
+
 /// Mutates the given ui to display a small card containing a skill by name.
 /// - `ui`: The UI context to draw on.
 /// - `skill`: The name of the skill to display.
 /// - `color`: The background color of the skill card.
 /// - `text_color`: The text color of the skill name.
-/// - `max_pos`: The maximum position for the skill card.
-pub fn skill_frameplate(ui: &mut Ui, skill: &str, color: Color32, text_color: Color32, font_size: f32) -> () {
-    let frame = Frame::new();
+/// - `icon`: An optional path to an icon to display before the skill name.
+/// - `font_size`: The font size of the skill name.
+/// - `style`: The card's height, margin, corner radius and spacing.
+pub fn skill_frameplate_sized(ui: &mut Ui, skill: &str, color: Color32, text_color: Color32, icon: &Option<String>, font_size: f32, style: &SkillChipStyle) {
     // Make the frame's stroke a stronger version of the color given
     let stroke = Stroke::new(2.0, color.blend(Color32::from_black_alpha(100)));
-    let text_galley = ui.fonts(|f| f.layout_no_wrap(skill.to_string(), FontId::default(), text_color));
-    let required_space = text_galley.size() + Vec2::splat(3.0);
+    let font_id = FontId::new(font_size, egui::FontFamily::Proportional);
+    let text_galley = ui.fonts(|f| f.layout_no_wrap(skill.to_string(), font_id.clone(), text_color));
+    // The frame adds its margin and stroke around the contents
+    let chrome = style.inner_margin.sum() + Vec2::splat(2.0 * stroke.width);
+    let mut required_space = text_galley.size() + chrome;
+    if icon.is_some() {
+        // Account for the icon and the spacing between it and the label
+        required_space.x += 16.0 + style.spacing;
+    }
+    if let Some(height) = style.height {
+        required_space.y = height;
+    }
     // Since frames don't auto-wrap, wrap if we do not have enough space
     if ui.available_rect_before_wrap().width() < required_space.x {
         log::debug!("Not enough space for skill: {}", skill);
         ui.end_row();
     }
-    let mut frame_ui = frame
-        .fill(color)
-        .inner_margin(2.0)
-        .outer_margin(0.0)
-        .corner_radius(CornerRadius::same(1))
-        .stroke(stroke)
-        .begin(ui);
-    {
-        frame_ui.content_ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
-        frame_ui.content_ui.label(egui::RichText::new(skill).color(text_color).font(FontId::new(font_size, egui::FontFamily::Proportional)));
+    let card_style = CardStyle {
+        fill: color,
+        stroke,
+        corner_radius: style.corner_radius.unwrap_or(ui.visuals().widgets.noninteractive.corner_radius),
+        inner_margin: style.inner_margin,
+        ..Default::default()
+    };
+    // Keyed by the name, so chips in a loop don't share state
+    ui.push_id(skill, |ui| card(ui, card_style, |ui| {
+        ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+        // Only what is left inside the margin and stroke is available for the row
+        let row_height = style.height.map(|height| (height - chrome.y).max(0.0));
+        ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = style.spacing;
+            if let Some(row_height) = row_height {
+                ui.set_min_height(row_height);
+                ui.set_max_height(row_height);
+            }
+            if let Some(icon) = icon {
+                let image = Image::new(icon).fit_to_exact_size(Vec2::new(16.0, 16.0));
+                ui.add(image);
+            }
+            ui.label(egui::RichText::new(skill).color(text_color).font(font_id));
+        });
+    }));
+}
+
+/// Inner margin of a [`timeline`] entry's card, which the dots are offset by to line up with the titles.
+const TIMELINE_ENTRY_MARGIN: i8 = 8;
+
+/// How many tags a project card shows before folding the rest into a "+N" chip, see [`tag_chips`].
+/// - `screen_size`: The layout tier, as narrower cards fit fewer chips.
+pub fn max_visible_tags(screen_size: ScreenSize) -> usize {
+    match screen_size {
+        ScreenSize::Small => 3,
+        ScreenSize::Medium => 4,
+        ScreenSize::Large => 5,
+    }
+}
+
+/// Mutates the given ui to display tags as chips, the first `max_visible` of them followed by a "+N" chip for the
+/// rest, which lists them on hover. Returns whether the "+N" chip was clicked, asking to show every tag.
+/// - `ui`: The UI context to draw on.
+/// - `tags`: The tags to show.
+/// - `root_url`: The root url used to resolve the tags' icons.
+/// - `max_visible`: How many tags are shown before the rest are folded away. `usize::MAX` shows them all.
+pub fn tag_chips(ui: &mut Ui, tags: &[Tag], root_url: &str, max_visible: usize) -> bool {
+    const FONT_SIZE: f32 = 12.0;
+    // Folding away a single tag would save no room
+    let shown = if tags.len() > max_visible.saturating_add(1) { max_visible } else { tags.len() };
+    for tag in &tags[..shown] {
+        tag_chip(ui, tag, root_url, FONT_SIZE);
+    }
+    let hidden = &tags[shown..];
+    if hidden.is_empty() {
+        return false;
+    }
+
+    let color = Tag::NEUTRAL_COLOR;
+    let text_color = best_text_color(color);
+    let style = CardStyle {
+        fill: color,
+        hover_fill: Some(color.blend(Color32::from_white_alpha(24))),
+        // Matches the stroke `skill_frameplate_sized` gives the other chips
+        stroke: Stroke::new(2.0, color.blend(Color32::from_black_alpha(100))),
+        corner_radius: ui.visuals().widgets.noninteractive.corner_radius,
+        inner_margin: SkillChipStyle::default().inner_margin,
+        sense: Sense::click(),
+        ..Default::default()
+    };
+    let response = card(ui, style, |ui| {
+        ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+        // The card takes the clicks, so the label must not select text
+        let text = egui::RichText::new(format!("+{}", hidden.len())).color(text_color).font(FontId::new(FONT_SIZE, egui::FontFamily::Proportional));
+        ui.add(egui::Label::new(text).selectable(false));
+    });
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), format!("Show {} more tags", hidden.len())));
+    paint_focus_ring(ui, &response);
+    let names: Vec<&str> = hidden.iter().map(|tag| tag.name.as_str()).collect();
+    response.on_hover_cursor(egui::CursorIcon::PointingHand).on_hover_text(names.join(", ")).clicked()
+}
+
+/// Mutates the given ui to display entries along a vertical line, each marked by a dot and shown as a [`card`].
+/// On [`ScreenSize::Large`] the line runs down the middle with entries alternating sides, otherwise it runs
+/// along the left edge with the entries stacked to its right.
+/// - `ui`: The UI context to draw on.
+/// - `entries`: The entries, top to bottom.
+/// - `screen_size`: The layout tier, picking between the alternating and stacked layouts.
+pub fn timeline(ui: &mut Ui, entries: &[TimelineEntry], screen_size: ScreenSize) {
+    const DOT_RADIUS: f32 = 5.0;
+    const GAP: f32 = 12.0; // Between the line and the cards
+    if entries.is_empty() {
+        return;
     }
-    frame_ui.paint(ui);
-    let _ = frame_ui.allocate_space(ui);
-    // frame_ui
+    let alternate = screen_size == ScreenSize::Large;
+    let full = ui.available_rect_before_wrap();
+    let line_x = if alternate { full.center().x } else { full.left() + DOT_RADIUS + 1.0 };
+    // Reserve a slot for the line, filled in once the last dot is placed, so the dots cover it
+    let line_slot = ui.painter().add(egui::Shape::Noop);
+    let dot_color = ui.visuals().selection.bg_fill;
+    let mut dots_y = Vec::with_capacity(entries.len());
+    for (idx, entry) in entries.iter().enumerate() {
+        let top = ui.cursor().top();
+        let on_left = alternate && idx % 2 == 0;
+        let (side, layout) = if on_left {
+            (Rect::from_min_max(pos2(full.left(), top), pos2(line_x - GAP, full.bottom())), egui::Layout::top_down(egui::Align::Max))
+        } else {
+            (Rect::from_min_max(pos2(line_x + GAP, top), pos2(full.right(), full.bottom())), egui::Layout::top_down(egui::Align::Min))
+        };
+        let response = ui.push_id(idx, |ui| {
+            ui.scope_builder(UiBuilder::new().max_rect(side).layout(layout), |ui| timeline_entry(ui, entry)).inner
+        }).inner;
+        // Line the dot up with the entry's title
+        let dot_y = response.rect.top() + f32::from(TIMELINE_ENTRY_MARGIN) + ui.text_style_height(&egui::TextStyle::Body) / 2.0;
+        ui.painter().circle(pos2(line_x, dot_y), DOT_RADIUS, dot_color, Stroke::new(2.0, ui.visuals().panel_fill));
+        dots_y.push(dot_y);
+        if idx + 1 < entries.len() {
+            ui.add_space(8.0);
+        }
+    }
+    if let (Some(&top), Some(&bottom)) = (dots_y.first(), dots_y.last()) {
+        let stroke = Stroke::new(2.0, ui.visuals().widgets.noninteractive.bg_stroke.color);
+        ui.painter().set(line_slot, egui::Shape::line_segment([pos2(line_x, top), pos2(line_x, bottom)], stroke));
+    }
+}
+
+/// One [`timeline`] entry: its title, organization and period over the description.
+fn timeline_entry(ui: &mut Ui, entry: &TimelineEntry) -> Response {
+    card(ui, CardStyle { inner_margin: Margin::same(TIMELINE_ENTRY_MARGIN), ..CardStyle::group(ui.style()) }, |ui| {
+        ui.style_mut().wrap_mode = Some(TextWrapMode::Wrap);
+        ui.label(egui::RichText::new(&entry.title).strong());
+        ui.label(egui::RichText::new(format!("{} · {}", entry.org, entry.period)).small().weak());
+        if !entry.description.trim().is_empty() {
+            ui.label(&entry.description);
+        }
+    })
+}
+
+/// Font sizes of the least and most proficient skills in [`skill_cloud`].
+pub const SKILL_CLOUD_FONT_RANGE: (f32, f32) = (12.0, 28.0);
+
+/// Mutates the given ui to display skills as a word cloud, each card's font scaled by the skill's `level`.
+/// Skills without a level use the median size of those with one. Cards wrap onto new rows when out of space.
+/// - `ui`: The UI context to draw on.
+/// - `skills`: The skills to display, in order.
+/// - `root_url`: The url the skills' icon paths are relative to.
+pub fn skill_cloud(ui: &mut Ui, skills: &[&Skill], root_url: &str) {
+    let sizes = skill_cloud_font_sizes(skills);
+    ui.horizontal_wrapped(|ui| {
+        for (skill, font_size) in skills.iter().zip(sizes) {
+            skill_frameplate(ui, &skill.name, skill.color(), skill.text_color(), &skill.icon_uri(root_url), font_size);
+        }
+    });
+}
+
+/// The font size of each skill in [`skill_cloud`], in order.
+fn skill_cloud_font_sizes(skills: &[&Skill]) -> Vec<f32> {
+    let (min, max) = SKILL_CLOUD_FONT_RANGE;
+    let (lowest, highest) = (*Skill::LEVELS.start() as f32, *Skill::LEVELS.end() as f32);
+    let size = |level: f32| lerp(min..=max, ((level - lowest) / (highest - lowest)).clamp(0.0, 1.0));
+    let mut levels: Vec<f32> = skills.iter().filter_map(|skill| skill.level).map(f32::from).collect();
+    levels.sort_by(f32::total_cmp);
+    let median = match levels.len() {
+        0 => (lowest + highest) / 2.0,
+        n if n % 2 == 0 => (levels[n / 2 - 1] + levels[n / 2]) / 2.0,
+        n => levels[n / 2],
+    };
+    skills.iter().map(|skill| size(skill.level.map_or(median, f32::from))).collect()
 }
 
-/// Mutates the given ui to display a social link with an optional icon
+/// Mutates the given ui to display a social link with an optional icon.
+/// The link is a clickable [`card`], filled and underlined while hovered or focused.
 /// - `ui`: The UI context to draw on.
 /// - `display`: The text to display for the link.
 /// - `link`: The URL to open when the link is clicked.
-/// - `icon`: An optional path to an icon to display next to the link.
-pub fn socials(ui: &mut Ui, display: &str, link: &str, icon: &Option<String>, font_size: f32) {
-    let frame = Frame::new();
-    let response = ui.scope_builder(
-    UiBuilder::new()
-        .sense(Sense::click()),
-    |ui| {
-            let mut frame_ui = frame
-                .inner_margin(2.0)
-                .outer_margin(0.0)
-                .corner_radius(CornerRadius::same(1))
-                .begin(ui);
-            {
-                frame_ui.content_ui.horizontal(|ui| {
-                    if let Some(icon) = icon {
-                        let image = Image::new(icon).fit_to_exact_size(Vec2::new(16.0, 16.0));
-                        ui.add(image);
-                    }
-                    let base_text_color = ui.visuals().text_color();
-                    ui.style_mut().interaction.selectable_labels = false;
-                    ui.label(egui::RichText::new(display).color(base_text_color.blend(Color32::from_rgb(base_text_color.r(), base_text_color.g(), 255))).font(FontId::new(font_size, egui::FontFamily::Proportional)));
-                });
-            }
-            let response = frame_ui.allocate_space(ui);
-            if response.hovered() {
-                frame_ui.frame.fill = ui.visuals().noninteractive().bg_stroke.color;;
-            }
-            frame_ui.paint(ui);
-            response
-        },
-    );
-    if response.response.clicked() {
-        open_link(link, "_blank");
-    }
-    if response.response.hovered() {
+/// - `icon`: An optional path to an icon to display next to the link, drawn at 16×16. An `.svg` is rasterized
+///   at the display's pixel density, so it stays crisp on high-DPI screens.
+pub fn socials(ui: &mut Ui, display: &str, link: &str, icon: &Option<String>, font_size: f32) -> Response {
+    // Keyed by the link, so per-link state doesn't bleed between links laid out in a loop
+    ui.push_id(link, |ui| {
+        let link_color = ui.visuals().hyperlink_color;
+        let style = CardStyle {
+            hover_fill: Some(ui.visuals().noninteractive().bg_stroke.color),
+            corner_radius: CornerRadius::same(1),
+            inner_margin: Margin::same(2),
+            sense: Sense::click(),
+            min_size: min_touch_target(ui.ctx()),
+            ..Default::default()
+        };
+        let response = card(ui, style, |ui| {
+            ui.with_layout(egui::Layout::left_to_right(egui::Align::Center), |ui| {
+                if let Some(icon) = icon {
+                    ui.add(Image::new(icon).fit_to_exact_size(Vec2::new(16.0, 16.0)));
+                }
+                // The card takes the clicks, so the label must not select text
+                ui.add(egui::Label::new(egui::RichText::new(display).color(link_color).font(FontId::new(font_size, egui::FontFamily::Proportional))).selectable(false));
+            });
+        });
+        if response.hovered() || focus_visible(ui, &response) {
+            paint_underline(ui, response.rect, Margin::same(2), Some(link_color), Vec2::ZERO, false);
+        }
+
+        if response.clicked() {
+            track(ui.ctx(), "link_click", &[("url", link)]);
+            open_link(ui.ctx(), link, link_target(ui.ctx()));
+        }
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Link, ui.is_enabled(), display));
+        paint_focus_ring(ui, &response);
+        #[cfg(debug_assertions)]
+        if let Some(problem) = link_problem(link) {
+            // Flag content typos (e.g. in `data.toml`) on the page rather than only in the console
+            let font_id = FontId::new(font_size, egui::FontFamily::Proportional);
+            let glyph_rect = ui.painter().text(response.rect.right_center() + vec2(4.0, 0.0), egui::Align2::LEFT_CENTER, "⚠", font_id, ui.visuals().error_fg_color);
+            ui.interact(glyph_rect, response.id.with("invalid_link"), Sense::hover())
+                .on_hover_text(format!("This link won't open: {problem}.\n{link}"));
+        }
+        // Reveal where the link goes before it is clicked
+        response.on_hover_text(link)
+    }).inner
+}
+
+/// Whether the widget has focus that was reached with the keyboard, like CSS `:focus-visible`.
+/// Focus gained by clicking stays invisible until the keyboard is used again.
+/// - `ui`: The UI context the widget is in.
+/// - `response`: The response of the widget.
+pub fn focus_visible(ui: &Ui, response: &Response) -> bool {
+    response.has_focus() && keyboard_modality(ui.ctx())
+}
+
+/// Tracks whether the last input came from the keyboard rather than a pointer, remembered across frames.
+fn keyboard_modality(ctx: &egui::Context) -> bool {
+    let id = egui::Id::new("keyboard_modality");
+    let (key_pressed, pointer_pressed) = ctx.input(|input| (
+        input.events.iter().any(|event| matches!(event, egui::Event::Key { pressed: true, .. })),
+        input.pointer.any_pressed(),
+    ));
+    ctx.data_mut(|data| {
+        let keyboard = data.get_temp_mut_or_default::<bool>(id);
+        if pointer_pressed {
+            *keyboard = false;
+        } else if key_pressed {
+            *keyboard = true;
+        }
+        *keyboard
+    })
+}
+
+/// Outlines the widget while it has keyboard focus, so Tab navigation is visible on every interactive element.
+/// Pointer users don't get a ring after clicking, see [`focus_visible`].
+/// - `ui`: The UI context to draw on.
+/// - `response`: The response of the widget to outline.
+pub fn paint_focus_ring(ui: &Ui, response: &Response) {
+    if focus_visible(ui, response) {
+        let stroke = ui.visuals().widgets.hovered.fg_stroke;
+        let stroke = Stroke::new(stroke.width.max(2.0), stroke.color);
+        ui.painter().rect_stroke(response.rect.expand(2.0), ui.visuals().widgets.hovered.corner_radius, stroke, egui::StrokeKind::Outside);
+    }
+}
+
+/// Mutates the given ui to add a clickable brand logo, fit within `size` without stretching and centered in it.
+/// The corners are rounded relative to the size the logo is drawn at, so square logos stay circular while
+/// wordmarks and other wide logos are only softened.
+/// Shows a pointing hand on hover like [`socials`]; the caller decides what a click does (e.g. return home).
+/// - `ui`: The UI context to draw on.
+/// - `source`: The logo image, e.g. from [`crate::data::Data::logo_source`]. An `.svg` is rasterized at the size it is drawn.
+/// - `size`: The box the logo is fit into.
+/// - `label`: Hover text and accessible label describing what a click does.
+pub fn logo(ui: &mut Ui, source: egui::ImageSource<'_>, size: Vec2, label: &str) -> Response {
+    let (rect, response) = ui.allocate_exact_size(size, Sense::click());
+    paint_logo(ui, source, rect);
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::ImageButton, ui.is_enabled(), label));
+    if response.hovered() {
         ui.output_mut(|o| o.cursor_icon = egui::CursorIcon::PointingHand);
     }
+    response.on_hover_text(label)
+}
+
+/// Paints a logo centered in `rect` at its own aspect ratio, rounded like [`logo`].
+/// - `ui`: The UI context to draw on.
+/// - `source`: The logo image.
+/// - `rect`: The box the logo is fit into.
+pub fn paint_logo(ui: &Ui, source: egui::ImageSource<'_>, rect: Rect) {
+    let image = Image::new(source).maintain_aspect_ratio(true).max_size(rect.size());
+    // Until the image has loaded its aspect ratio is unknown, so fill the box
+    let drawn_size = image.load_and_calc_size(ui, rect.size()).unwrap_or(rect.size());
+    image.corner_radius(drawn_size.min_elem() / 1.5).paint_at(ui, Rect::from_center_size(rect.center(), drawn_size));
+}
+
+/// Computes the normalized position of a point along a gradient spanning `rect`.
+///
+/// The returned `t` is `0.0` at the start of the gradient and `1.0` at its end, or `0.5` everywhere when the rect
+/// is degenerate along the direction. Points outside of `rect` may fall outside of `0.0..=1.0`.
+/// - `rect`: The `egui::Rect` the gradient spans.
+/// - `angle_rad`: The angle of the gradient in radians, see [`gradient_direction`].
+/// - `pos`: The point to compute the factor for.
+pub fn gradient_factor_at(rect: Rect, angle_rad: f32, pos: Pos2) -> f32 {
+    let rot = gradient_direction(angle_rad);
+    // The two corners furthest along and against the direction bound the projection
+    let half_range = 0.5 * (rect.width() * rot.x.abs() + rect.height() * rot.y.abs());
+    let proj_range = 2.0 * half_range;
+    if proj_range.abs() < f32::EPSILON {
+        0.5
+    } else {
+        ((pos - rect.center()).dot(rot) + half_range) / proj_range
+    }
+}
+
+/// The unit vector a gradient runs along, from its start color to its end color.
+/// 0 runs bottom to top and the angle turns clockwise, so a quarter turn runs left to right. Any angle is accepted
+/// and wrapped into `0..2π`. Components within rounding error of zero are snapped to it, so axis-aligned gradients
+/// (multiples of `π/2`) don't pick up a sliver of the other axis, which would keep a zero-width or zero-height
+/// rect from falling back to its midpoint.
+/// - `angle_rad`: The angle of the gradient in radians.
+fn gradient_direction(angle_rad: f32) -> Vec2 {
+    const SNAP: f32 = 1e-6;
+    let (sin, cos) = angle_rad.rem_euclid(std::f32::consts::TAU).sin_cos();
+    let snap = |component: f32| if component.abs() < SNAP { 0.0 } else { component };
+    Vec2::new(snap(sin), snap(-cos))
 }
 
+/// Number of cells per axis in the mesh built by [`paint_angular_gradient`].
+/// The GPU interpolates vertex colors in gamma space, so a finer mesh keeps the gradient close to linear light.
+const GRADIENT_SUBDIVISIONS: u32 = 8;
+
 /// Paints a rectangle with a linear gradient with intensity controls.
 ///
+/// Colors are blended in linear light, see [`GRADIENT_SUBDIVISIONS`]. Alpha is interpolated
+/// premultiplied, so fading to [`Color32::TRANSPARENT`] keeps the hue instead of darkening it.
 /// - `painter`: The `egui::Painter` to draw with.
 /// - `rect`: The `egui::Rect` to fill with the gradient.
 /// - `start_color`: The color at the start of the gradient.
 /// - `end_color`: The color at the end of the gradient.
-/// - `angle_rad`: The angle of the gradient in radians. 0 is vertical top-to-bottom.
+/// - `angle_rad`: The angle of the gradient in radians, see [`gradient_direction`]. Use [`paint_angular_gradient_deg`] for degrees.
 /// - `intensity`: A `Vec2` to control the blending importance.
 pub fn paint_angular_gradient(
     painter: &Painter,
@@ -422,133 +1065,1632 @@ pub fn paint_angular_gradient(
     angle_rad: f32,
     intensity: Vec2,
 ) {
-    let rot = Vec2::new(angle_rad.sin(), -angle_rad.cos());
-    let rect_center = rect.center();
-    let corners = [
-        rect.left_top(),
-        rect.right_top(),
-        rect.right_bottom(),
-        rect.left_bottom(),
-    ];
-
-    let projections = corners.map(|corner| (corner - rect_center).dot(rot));
-    let min_proj = projections.iter().fold(f32::INFINITY, |a, &b| a.min(b));
-    let max_proj = projections.iter().fold(f32::NEG_INFINITY, |a, &b| a.max(b));
-    let proj_range = max_proj - min_proj;
+    painter.add(angular_gradient_mesh(rect, start_color, end_color, angle_rad, intensity));
+}
 
-    // Clamp intensities to prevent division by zero or negative values.
-    let safe_intensity = Vec2::new(intensity.x.max(f32::EPSILON), intensity.y.max(f32::EPSILON));
+/// Like [`paint_angular_gradient`], with the angle in degrees: 0 runs bottom to top and 90 left to right.
+pub fn paint_angular_gradient_deg(
+    painter: &Painter,
+    rect: Rect,
+    start_color: Color32,
+    end_color: Color32,
+    angle_deg: f32,
+    intensity: Vec2,
+) {
+    paint_angular_gradient(painter, rect, start_color, end_color, angle_deg.to_radians(), intensity);
+}
 
-    // Define the blend region in normalized (0-1) space.
-    // An intensity of 2.0 for the start color means it should hold until the 0.5 mark.
-    // The formula is 0.5 - 0.5 / intensity.
-    let start_blend_point = 0.5 - (0.5 / safe_intensity.x);
-    let end_blend_point = 0.5 + (0.5 / safe_intensity.y);
+/// How long one fade out and back in of the [`status_pill`] dot takes, in seconds.
+pub const STATUS_PULSE_PERIOD: f32 = 1.6;
 
-    let mut mesh = Mesh::default();
-    let mut vertices = Vec::with_capacity(4);
-    let start_rgba = Rgba::from(start_color);
-    let end_rgba = Rgba::from(end_color);
+/// Mutates the given ui to add a small pill with a status line, e.g. "Currently building: X", after a pulsing dot.
+/// The pill is a subtle [`card`] in the widget colors with fully rounded ends. The dot holds still when
+/// `animation_time` is zero (reduced motion).
+/// - `ui`: The UI context to draw on.
+/// - `status`: The text of the pill.
+/// - `dot_color`: The color of the dot, e.g. the palette's accent.
+/// - `font_size`: The font size of the text, the dot is sized relative to it.
+pub fn status_pill(ui: &mut Ui, status: &str, dot_color: Color32, font_size: f32) -> Response {
+    let visuals = &ui.visuals().widgets.noninteractive;
+    let text_color = visuals.text_color();
+    let style = CardStyle {
+        fill: visuals.weak_bg_fill,
+        stroke: visuals.bg_stroke,
+        // Clamped to half the pill's height when painted, so the ends are fully round
+        corner_radius: CornerRadius::same(u8::MAX),
+        inner_margin: Margin::symmetric(10, 4),
+        ..Default::default()
+    };
+    let response = card(ui, style, |ui| {
+        ui.horizontal(|ui| {
+            let (dot_rect, dot_response) = ui.allocate_exact_size(Vec2::splat(font_size * 0.6), Sense::hover());
+            let pulse = if ui.style().animation_time > 0.0 {
+                // Ease toward whichever end the current half period points at, so the dot breathes in and out
+                let half_period = f64::from(STATUS_PULSE_PERIOD / 2.0);
+                let phase = now(ui.ctx()) % f64::from(STATUS_PULSE_PERIOD);
+                // The animation repaints while it moves, this wakes the dot up again when the next half period starts
+                ui.ctx().request_repaint_after(std::time::Duration::from_secs_f64(half_period - phase % half_period));
+                animate_bool(ui.ctx(), dot_response.id.with("pulse"), phase < half_period, STATUS_PULSE_PERIOD / 2.0)
+            } else {
+                1.0
+            };
+            let radius = dot_rect.width() / 2.0;
+            ui.painter().circle_filled(dot_rect.center(), radius * (1.0 + pulse), dot_color.gamma_multiply(0.25 * (1.0 - pulse)));
+            ui.painter().circle_filled(dot_rect.center(), radius, dot_color.gamma_multiply(0.6 + 0.4 * pulse));
+            ui.add(egui::Label::new(egui::RichText::new(status).color(text_color).font(FontId::new(font_size, egui::FontFamily::Proportional))).selectable(false));
+        });
+    });
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, ui.is_enabled(), status));
+    response
+}
 
-    for i in 0..4 {
-        // Calculate the linear interpolation factor `t` for this vertex.
-        let t = if proj_range.abs() < f32::EPSILON {
-            0.5
-        } else {
-            (projections[i] - min_proj) / proj_range
-        };
+/// Mutates the given ui to add a full-width hero banner: a name and tagline centered over an angular gradient.
+/// The name is filled with a gradient of its own, see [`gradient_text`]. The text gets a soft shadow in the background color so it stays legible over either end of the gradient.
+/// - `ui`: The UI context to draw on.
+/// - `name`: The headline, e.g. the portfolio owner's name.
+/// - `tagline`: A short line shown under the name.
+/// - `gradient`: The start color, end color and angle of the background, see [`paint_angular_gradient`].
+/// - `height`: The height of the banner.
+/// - `font_size`: The font size of the name, the tagline is set at half of it.
+pub fn hero(ui: &mut Ui, name: &str, tagline: &str, gradient: (Color32, Color32, f32), height: f32, font_size: f32) -> Response {
+    const SHADOW_OFFSET: Vec2 = vec2(1.5, 1.5);
+    let (rect, response) = ui.allocate_exact_size(vec2(ui.available_width(), height), Sense::hover());
+    if !ui.is_rect_visible(rect) {
+        return response;
+    }
 
-        // Remap `t` based on the intensity-derived blend points.
-        let blend_factor = emath::remap_clamp(t, start_blend_point..=end_blend_point, 0.0..=1.0);
-        
-        // Interpolate the color using the final blend factor.
-        let color = lerp(start_rgba..=end_rgba, blend_factor);
+    let painter = ui.painter_at(rect);
+    let (start_color, end_color, angle_rad) = gradient;
+    paint_angular_gradient(&painter, rect, start_color, end_color, angle_rad, Vec2::splat(1.0));
 
-        vertices.push(epaint::Vertex {
-            pos: corners[i],
-            uv: Pos2::ZERO,
-            color: color.into(),
-        });
+    let text_color = ui.visuals().strong_text_color();
+    let shadow_color = ui.visuals().extreme_bg_color.gamma_multiply(0.6);
+    let font_family = egui::FontFamily::Proportional;
+    let name_font = FontId::new(font_size, font_family.clone());
+    // Measured with the same wrap width `gradient_text` gets below, so it lays the name out identically
+    let name_size = painter.layout(name.to_owned(), name_font.clone(), text_color, rect.width()).size();
+    let tagline_galley = painter.layout(tagline.to_owned(), FontId::new(font_size * 0.5, font_family), text_color, rect.width());
+    let spacing = font_size * 0.25;
+    let text_height = name_size.y + spacing + tagline_galley.size().y;
+    let name_pos = pos2(rect.center().x - name_size.x / 2.0, rect.center().y - text_height / 2.0);
+    let tagline_pos = pos2(rect.center().x - tagline_galley.size().x / 2.0, name_pos.y + name_size.y + spacing);
+    painter.galley_with_override_text_color(tagline_pos + SHADOW_OFFSET, tagline_galley.clone(), shadow_color);
+    painter.galley(tagline_pos, tagline_galley, text_color);
+
+    // The name runs from the text color into the link color, left to right
+    let mut name_ui = ui.new_child(UiBuilder::new().max_rect(Rect::from_min_size(name_pos, vec2(rect.width(), name_size.y))));
+    let name_response = gradient_text(&mut name_ui, name, name_font, text_color, ui.visuals().hyperlink_color, std::f32::consts::FRAC_PI_2);
+    set_heading_role(ui, name_response.id, 1);
+
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, true, tagline));
+    response
+}
+
+/// Mutates the given ui to add a label whose glyphs are filled with an angular gradient, e.g. for headings.
+/// The text gets a soft shadow in the background color like [`hero`], so it stays legible in either theme.
+/// - `ui`: The UI context to draw on.
+/// - `text`: The text to show, wrapped at the available width.
+/// - `font_id`: The font of the text.
+/// - `start`: The color at the start of the gradient.
+/// - `end`: The color at the end of the gradient.
+/// - `angle`: The angle of the gradient in radians, see [`paint_angular_gradient`].
+pub fn gradient_text(ui: &mut Ui, text: &str, font_id: FontId, start: Color32, end: Color32, angle: f32) -> Response {
+    const SHADOW_OFFSET: Vec2 = vec2(1.0, 1.0);
+    let galley = ui.painter().layout(text.to_owned(), font_id, Color32::WHITE, ui.available_width());
+    let (rect, response) = ui.allocate_exact_size(galley.size(), Sense::hover());
+    if ui.is_rect_visible(rect) {
+        let shadow_color = ui.visuals().extreme_bg_color.gamma_multiply(0.6);
+        ui.painter().galley_with_override_text_color(rect.min + SHADOW_OFFSET, galley.clone(), shadow_color);
+        ui.painter().galley(rect.min, gradient_galley(galley, start, end, angle), start);
     }
-    
-    mesh.indices.extend_from_slice(&[0, 1, 2, 0, 2, 3]);
-    mesh.vertices = vertices;
-    painter.add(mesh);
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, true, text));
+    response
 }
 
-pub fn add_highlighted_project(ui: &mut egui::Ui, ctx: &egui::Context, root_url: &String, project: &mut ProjectHighlight) {
-    Frame::group(ui.style()).stroke(Stroke::NONE).fill(Color32::TRANSPARENT).outer_margin(Margin::symmetric(8, 4)).show(ui, |ui| {
-        ui.with_layout(egui::Layout::top_down(egui::Align::LEFT), |ui| {
-            let mut img_rect = Rect::NOTHING;
-            let main_response = ui.horizontal(|ui| {
-                if let Some(thumbnail) = project.get_set_thumbnail(root_url, ctx) {
-                    let img_response = ui.add(Button::image(Image::new(thumbnail).fit_to_exact_size(Vec2::new(128.0, 128.0)).corner_radius(2.0)));
-                    img_rect = img_response.rect;
-                    if img_response.clicked() {
-                        open_link(&project.external_link, "_blank");
-                    }
-                } else {
-                    ui.label("No thumbnail available");
-                }
-                ui.vertical(|ui| {
-                    ui.style_mut().wrap_mode = Some(TextWrapMode::Wrap);
-                    ui.heading(&project.title);
-                    ui.horizontal(|ui| {
-                        for tag in &project.tags {
-                            skill_frameplate(ui, &tag.name, tag.color(), tag.text_color(), 12.0);
-                        }
-                    });
-                    ui.monospace(&project.description);
-                });
-            }).response;
-            // If img_rect has been assigned, draw a corner line down from the bottom center of the image
-            if img_rect.ne(&Rect::NOTHING) && project.highlight_imgs.len() > 0 {
-                let top_color = Color32::from_rgba_unmultiplied(94, 84, 142, 127);
-                let line_start = img_rect.center_bottom();
-                let vertical_end = pos2(img_rect.center().x, main_response.rect.bottom() + 66.0);
-                let horizontal_end = vertical_end + Vec2::new(64.0, 0.0);
-                ui.painter().line_segment([line_start, vertical_end], (3.0, ui.visuals().extreme_bg_color.blend(top_color)));
-                ui.painter().line_segment([vertical_end, horizontal_end], (3.0, ui.visuals().extreme_bg_color.blend(top_color)));
-                ui.painter().line_segment([line_start, vertical_end], (1.0, Color32::from_gray(40)));
-                ui.painter().line_segment([vertical_end, horizontal_end], (1.0, Color32::from_gray(40)));
-                ui.add_space(12.0);
-                ui.horizontal(|ui| {
-                    ui.add_space(144.0);
-                    ui.spacing_mut().item_spacing = vec2(12.0, 0.0);
-                    let persistent_id = ui.make_persistent_id(format!("highlight_{}_{}", project.title, project.highlight_imgs.len()));
-                    let some_modal_shown = ui.data(|data| data.get_temp(persistent_id)).unwrap_or(project.highlight_imgs.len());
-                    if some_modal_shown < project.highlight_imgs.len() {
-                        show_modal(ctx, ui, project.highlight_imgs.len(), some_modal_shown, &project.title, &(root_url.to_owned() + &project.highlight_imgs[some_modal_shown]), persistent_id);
-                    }
-                    for (idx, img_path) in project.highlight_imgs.iter().enumerate() {
-                        let image = Image::new(root_url.to_owned() + img_path).maintain_aspect_ratio(true).max_size(vec2(500.0, 112.0)).corner_radius(2.0);
-                        let size = image.calc_size(vec2(500.0, 500.0), None);
-                        let (image_rect, image_response) = ui.allocate_at_least(size, Sense::click());
-                        let hovered = image_response.hovered();
-                        // Image::new(root_url.to_owned() + img_path).fit_to_exact_size(Vec2::new(112.0 + 24.0 * f32::from(hovered), 112.0 + 24.0 * f32::from(hovered))).corner_radius(2.0)
-                        image.max_height(112.0 + 24.0 * f32::from(hovered)).paint_at(ui, image_rect.expand(12.0 * f32::from(hovered)).translate(vec2(8.0 * f32::from(hovered), 0.0)));
-                        if hovered {ui.add_space(16.0);}
-                        if image_response.clicked() {
-                            ui.data_mut(|data| {
-                                data.insert_persisted(persistent_id, idx);
-                            });
-                        }
-                        let should_show = ui.data(|data| {
-                            data.get_temp(persistent_id)
-                        }).unwrap_or(project.highlight_imgs.len()) == idx;
-                    }
-                });
-            }
+/// Recolors every glyph of the galley by its position along a gradient spanning the galley.
+/// - `galley`: The laid-out text.
+/// - `start`: The color at the start of the gradient.
+/// - `end`: The color at the end of the gradient.
+/// - `angle`: The angle of the gradient in radians, see [`paint_angular_gradient`].
+fn gradient_galley(mut galley: Arc<Galley>, start: Color32, end: Color32, angle: f32) -> Arc<Galley> {
+    let (start_rgba, end_rgba) = (Rgba::from(start), Rgba::from(end));
+    let galley_mut = Arc::make_mut(&mut galley);
+    let bounds = galley_mut.rect;
+    for placed in &mut galley_mut.rows {
+        let offset = placed.pos.to_vec2();
+        for vertex in &mut Arc::make_mut(&mut placed.row).visuals.mesh.vertices {
+            let t = gradient_factor_at(bounds, angle, vertex.pos + offset);
+            vertex.color = gradient_color(start_rgba, end_rgba, t, Vec2::splat(1.0));
+        }
+    }
+    galley
+}
+
+/// Paints a thin bar along the top of a scroll area's viewport, filled as far as the visitor has scrolled.
+/// The bar follows the offset over the style's `animation_time`, so it snaps with reduced motion, and is hidden while
+/// everything fits without scrolling.
+/// - `ctx`: The egui context to draw on.
+/// - `output`: What the vertical scroll area returned this frame.
+/// - `gradient`: The colors at the start and the end of the filled part.
+pub fn scroll_progress<R>(ctx: &egui::Context, output: &egui::scroll_area::ScrollAreaOutput<R>, gradient: (Color32, Color32)) {
+    const HEIGHT: f32 = 3.0;
+    let viewport = output.inner_rect;
+    let max_offset = output.content_size.y - viewport.height();
+    if max_offset <= 0.5 {
+        return;
+    }
+    let target = (output.state.offset.y / max_offset).clamp(0.0, 1.0);
+    let progress = animate_value(ctx, output.id.with("scroll_progress"), target, ctx.style().animation_time);
+    if progress <= 0.0 {
+        return;
+    }
+
+    egui::Area::new(output.id.with("scroll_progress_bar"))
+        .order(egui::Order::Foreground)
+        .fixed_pos(viewport.left_top())
+        .interactable(false)
+        .show(ctx, |ui| {
+            let filled = Rect::from_min_size(viewport.left_top(), vec2(viewport.width() * progress, HEIGHT));
+            let (start, end) = gradient;
+            paint_angular_gradient(ui.painter(), filled, start, end, std::f32::consts::FRAC_PI_2, Vec2::splat(1.0));
         });
-    });
+}
 
+/// When a scroll area's bar shows, set from `scrollbar` in `data.toml`, see [`scrollbar_style`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScrollbarVisibility {
+    #[default]
+    AutoHide, // Hidden until the area is hovered or scrolled, fading out again shortly after
+    Always,
 }
 
-pub fn show_modal(ctx: &egui::Context, ui: &egui::Ui, len: usize, idx: usize, title: &String, img_path: &String, id: egui::Id) {
-    let image = Image::new(img_path).maintain_aspect_ratio(true).fit_to_original_size(2.).corner_radius(4);
-    let frame = Frame::group(&ctx.style()).stroke(Stroke::NONE).fill(Color32::from_black_alpha(200)).inner_margin(Margin::symmetric(16, 16));
-    let modal_response = egui::Modal::new(id).frame(frame).show(ctx, |ui| {
-        let maximum_size = ctx.screen_rect().size() - vec2(32.0, 32.0);
+/// Returns `base` adjusted so a floating scroll bar follows `visibility`. Apply it to the ui the scroll area is
+/// shown in, as the bar is drawn with the style the area started with.
+/// - `ctx`: The egui context holding the scroll area's state.
+/// - `scroll_id`: The scroll area's persistent id, used to notice when it scrolls.
+/// - `base`: The theme's scroll style; its active opacities are what a shown bar uses.
+/// - `visibility`: Whether the bar hides while the area is idle.
+pub fn scrollbar_style(ctx: &egui::Context, scroll_id: egui::Id, base: egui::style::ScrollStyle, visibility: ScrollbarVisibility) -> egui::style::ScrollStyle {
+    const LINGER: f64 = 0.8; // How long the bar stays after scrolling stops
+    let shown = match visibility {
+        ScrollbarVisibility::Always => 1.0,
+        ScrollbarVisibility::AutoHide => {
+            let offset = egui::scroll_area::State::load(ctx, scroll_id).map_or(0.0, |state| state.offset.y);
+            let now = now(ctx);
+            let id = scroll_id.with("scrollbar_activity");
+            let scrolled_at = match ctx.data(|data| data.get_temp::<(f32, f64)>(id)) {
+                Some((last_offset, scrolled_at)) if last_offset == offset => scrolled_at,
+                Some(_) => now,
+                None => f64::NEG_INFINITY, // Don't flash the bar for the restored offset
+            };
+            ctx.data_mut(|data| data.insert_temp(id, (offset, scrolled_at)));
+            let scrolling = now - scrolled_at < LINGER;
+            if scrolling {
+                ctx.request_repaint_after(std::time::Duration::from_secs_f64(LINGER - (now - scrolled_at)));
+            }
+            animate_bool(ctx, id.with("fade"), scrolling, ctx.style().animation_time * 2.0)
+        }
+    };
+    egui::style::ScrollStyle {
+        dormant_handle_opacity: lerp(base.dormant_handle_opacity..=base.active_handle_opacity, shown),
+        dormant_background_opacity: lerp(base.dormant_background_opacity..=base.active_background_opacity, shown),
+        ..base
+    }
+}
+
+/// Images passed to [`preload`] that haven't finished loading yet, kept in the context's temporary data.
+#[derive(Clone, Default)]
+struct PreloadRegistry {
+    pending: Vec<String>,
+}
+
+impl PreloadRegistry {
+    fn id() -> egui::Id {
+        egui::Id::new("preload_registry")
+    }
+}
+
+/// Starts loading the given images right away, e.g. the above-the-fold assets from `new`, so they are ready once painted.
+/// Progress is tracked by [`poll_preloads`].
+/// - `ctx`: The egui context, with its image loaders installed.
+/// - `uris`: The images to load.
+pub fn preload(ctx: &egui::Context, uris: &[&str]) {
+    for uri in uris {
+        // Only the side effect of starting the load matters here
+        let _: egui::load::ImageLoadResult = ctx.try_load_image(uri, egui::SizeHint::default());
+    }
+    ctx.data_mut(|data| {
+        let registry = data.get_temp_mut_or_default::<PreloadRegistry>(PreloadRegistry::id());
+        registry.pending.extend(uris.iter().map(|uri| uri.to_string()));
+    });
+}
+
+/// Checks on the images passed to [`preload`], logging any that failed to load.
+/// Returns whether any are still loading, requesting a repaint so polling continues while they do.
+pub fn poll_preloads(ctx: &egui::Context) -> bool {
+    let mut pending = ctx.data_mut(|data| std::mem::take(&mut data.get_temp_mut_or_default::<PreloadRegistry>(PreloadRegistry::id()).pending));
+    pending.retain(|uri| match ctx.try_load_image(uri, egui::SizeHint::default()) {
+        Ok(egui::load::ImagePoll::Pending { .. }) => true,
+        Ok(egui::load::ImagePoll::Ready { .. }) => false,
+        Err(err) => {
+            log::warn!("Failed to preload {uri}: {err}");
+            false
+        }
+    });
+    let loading = !pending.is_empty();
+    if loading {
+        ctx.request_repaint_after(std::time::Duration::from_millis(100));
+    }
+    // Keep anything `preload` added meanwhile
+    ctx.data_mut(|data| data.get_temp_mut_or_default::<PreloadRegistry>(PreloadRegistry::id()).pending.extend(pending));
+    loading
+}
+
+/// A page section registered through [`register_section`], listed by [`table_of_contents`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SectionEntry {
+    pub title: String,
+    pub rect: Rect, // Where the section was laid out, in the coordinates of the ui that registered it
+}
+
+/// The sections registered over the last two passes, kept in the context's temporary data.
+#[derive(Clone, Default)]
+struct SectionRegistry {
+    pass: u64, // The pass `current` is being collected for
+    current: Vec<SectionEntry>,
+    previous: Vec<SectionEntry>, // Complete list from the pass before `pass`
+    scroll_to: Option<String>, // Title of the section to scroll into view the next time it registers
+}
+
+impl SectionRegistry {
+    fn id() -> egui::Id {
+        egui::Id::new("section_registry")
+    }
+}
+
+/// Records a page section for this pass, scrolling it into view if [`scroll_to_section`] asked for it.
+/// Sections are listed in the order they register, so register them in page order.
+/// - `ui`: The UI the section was laid out in (e.g. inside the page's scroll area).
+/// - `title`: The title listed in the table of contents.
+/// - `rect`: The area of the section to scroll to.
+pub fn register_section(ui: &Ui, title: &str, rect: Rect) {
+    let pass = ui.ctx().cumulative_pass_nr();
+    let scroll = ui.data_mut(|data| {
+        let registry = data.get_temp_mut_or_default::<SectionRegistry>(SectionRegistry::id());
+        if registry.pass != pass {
+            registry.previous = std::mem::take(&mut registry.current);
+            registry.pass = pass;
+        }
+        registry.current.push(SectionEntry { title: title.to_owned(), rect });
+        registry.scroll_to.take_if(|target| target == title).is_some()
+    });
+    if scroll {
+        ui.scroll_to_rect(rect, Some(egui::Align::TOP));
+    }
+}
+
+/// Scrolls the section with the given title into view once it next registers, see [`register_section`].
+pub fn scroll_to_section(ctx: &egui::Context, title: &str) {
+    ctx.data_mut(|data| data.get_temp_mut_or_default::<SectionRegistry>(SectionRegistry::id()).scroll_to = Some(title.to_owned()));
+    ctx.request_repaint();
+}
+
+/// The sections of the last complete pass, in page order.
+/// Reading them before the sections register this pass (e.g. from a top panel) yields the previous pass's.
+pub fn sections(ctx: &egui::Context) -> Vec<SectionEntry> {
+    let pass = ctx.cumulative_pass_nr();
+    ctx.data(|data| data.get_temp::<SectionRegistry>(SectionRegistry::id()).map(|registry| {
+        if registry.pass == pass { registry.previous } else { registry.current }
+    })).unwrap_or_default()
+}
+
+/// Mutates the given ui to add a section heading in the Heading text style, registered for the table of contents.
+/// Screen readers announce it as a heading of the given level.
+/// - `ui`: The UI context to draw on.
+/// - `title`: The text of the heading, also listed in the table of contents.
+/// - `level`: The heading level, `1` being the page title.
+pub fn section_heading(ui: &mut Ui, title: &str, level: usize) -> Response {
+    let response = ui.heading(egui::RichText::new(title).underline());
+    set_heading_role(ui, response.id, level);
+    register_section(ui, title, response.rect);
+    response
+}
+
+/// Mutates the given ui to add a full-width divider with a label centered on it, like "— Projects —".
+/// The lines fill the width left on either side of the label, in the separator color of the visuals.
+/// - `ui`: The UI context to draw on.
+/// - `text`: The label, in the weak text color.
+pub fn labeled_separator(ui: &mut Ui, text: impl Into<WidgetText>) -> Response {
+    const GAP: f32 = 8.0; // Between the label and each line
+    let galley = text.into().into_galley(ui, Some(TextWrapMode::Truncate), ui.available_width() - 2.0 * GAP, egui::TextStyle::Body);
+    let height = galley.size().y + ui.spacing().item_spacing.y;
+    let (rect, response) = ui.allocate_exact_size(vec2(ui.available_width(), height), Sense::hover());
+    if ui.is_rect_visible(rect) {
+        let text_rect = Rect::from_center_size(rect.center(), galley.size());
+        let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+        let y = rect.center().y;
+        for (from, to) in [(rect.left(), text_rect.left() - GAP), (text_rect.right() + GAP, rect.right())] {
+            if to > from {
+                ui.painter().hline(from..=to, y, stroke);
+            }
+        }
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, ui.is_enabled(), galley.text()));
+        ui.painter().galley(text_rect.min, galley, ui.visuals().weak_text_color());
+    }
+    response
+}
+
+/// Mutates the given ui to list the page's sections as links scrolling to each, see [`sections`].
+/// - `ui`: The UI context to draw on.
+/// - `font_size`: The font size of the links.
+pub fn table_of_contents(ui: &mut Ui, font_size: f32) {
+    for section in sections(ui.ctx()) {
+        let text = egui::RichText::new(&section.title).font(FontId::new(font_size, egui::FontFamily::Proportional));
+        let link = ui.add(ButtonWithUnderline::new(text).frame(false).inset([8.0, 8.0]))
+            .on_hover_text(format!("Jump to {}", section.title));
+        if link.clicked() {
+            scroll_to_section(ui.ctx(), &section.title);
+        }
+    }
+}
+
+/// Tells screen readers the widget is a heading of the given level.
+fn set_heading_role(ui: &Ui, id: egui::Id, level: usize) {
+    ui.ctx().accesskit_node_builder(id, |node| {
+        node.set_role(egui::accesskit::Role::Heading);
+        node.set_level(level);
+    });
+}
+
+/// Paints a rectangle fading from `color` to fully transparent, e.g. for a vignette behind text.
+/// See [`paint_angular_gradient`] for the parameters.
+pub fn paint_angular_fade(
+    painter: &Painter,
+    rect: Rect,
+    color: Color32,
+    angle_rad: f32,
+    intensity: Vec2,
+) {
+    paint_angular_gradient(painter, rect, color, Color32::TRANSPARENT, angle_rad, intensity);
+}
+
+/// Blends the gradient colors at the normalized position `t` along the gradient.
+/// - `start_rgba`: The color at the start of the gradient.
+/// - `end_rgba`: The color at the end of the gradient.
+/// - `t`: The position along the gradient, see [`gradient_factor_at`].
+/// - `intensity`: How long each color holds before blending, see [`paint_angular_gradient`].
+fn gradient_color(start_rgba: Rgba, end_rgba: Rgba, t: f32, intensity: Vec2) -> Color32 {
+    // Clamp intensities to prevent division by zero or negative values.
+    let safe_intensity = Vec2::new(intensity.x.max(f32::EPSILON), intensity.y.max(f32::EPSILON));
+
+    // Define the blend region in normalized (0-1) space.
+    // An intensity of 2.0 for the start color means it should hold until the 0.25 mark.
+    // The formula is 0.5 - 0.5 / intensity.
+    let start_blend_point = 0.5 - (0.5 / safe_intensity.x);
+    let end_blend_point = 0.5 + (0.5 / safe_intensity.y);
+
+    // Remap the linear interpolation factor `t` based on the intensity-derived blend points.
+    let blend_factor = emath::remap_clamp(t, start_blend_point..=end_blend_point, 0.0..=1.0);
+
+    // `Rgba` is linear, so the lerp happens in linear light before converting back to sRGB.
+    lerp(start_rgba..=end_rgba, blend_factor).into()
+}
+
+/// Builds the mesh painted by [`paint_angular_gradient`], a grid of
+/// [`GRADIENT_SUBDIVISIONS`] squared cells laid out row by row from the left-top corner.
+fn angular_gradient_mesh(
+    rect: Rect,
+    start_color: Color32,
+    end_color: Color32,
+    angle_rad: f32,
+    intensity: Vec2,
+) -> Mesh {
+    let start_rgba = Rgba::from(start_color);
+    let end_rgba = Rgba::from(end_color);
+
+    let steps = GRADIENT_SUBDIVISIONS;
+    let mut mesh = Mesh::default();
+    mesh.vertices.reserve(((steps + 1) * (steps + 1)) as usize);
+    for row in 0..=steps {
+        for col in 0..=steps {
+            let pos = rect.lerp_inside(vec2(col as f32 / steps as f32, row as f32 / steps as f32));
+
+            let t = gradient_factor_at(rect, angle_rad, pos);
+            mesh.vertices.push(epaint::Vertex {
+                pos,
+                uv: Pos2::ZERO,
+                color: gradient_color(start_rgba, end_rgba, t, intensity),
+            });
+        }
+    }
+
+    for row in 0..steps {
+        for col in 0..steps {
+            let top_left = row * (steps + 1) + col;
+            let bottom_left = top_left + steps + 1;
+            mesh.add_triangle(top_left, top_left + 1, bottom_left + 1);
+            mesh.add_triangle(top_left, bottom_left + 1, bottom_left);
+        }
+    }
+    mesh
+}
+
+/// Number of wedges in the triangle fan built by [`paint_conic_gradient`].
+const CONIC_SEGMENTS: u32 = 64;
+
+/// Paints a conic (angular sweep) gradient filling `rect`, rotating clockwise around `center`.
+///
+/// Each stop is a position in `0.0..=1.0` around the circle, starting at the top, and a color.
+/// Stops should be sorted by position. The last stop blends back into the first, so there is no seam.
+/// - `painter`: The `egui::Painter` to draw with.
+/// - `rect`: The `egui::Rect` to fill with the gradient.
+/// - `center`: The point the gradient sweeps around, clamped into `rect`.
+/// - `stops`: The color stops of the gradient.
+pub fn paint_conic_gradient(
+    painter: &Painter,
+    rect: Rect,
+    center: Pos2,
+    stops: &[(f32, Color32)],
+) {
+    if stops.is_empty() || !rect.is_finite() || rect.width() <= 0.0 || rect.height() <= 0.0 {
+        return;
+    }
+    let center = rect.clamp(center);
+
+    // Distance from the center to the edge of the rect along a direction
+    let ray_length = |dir: Vec2| {
+        let to_edge = |origin: f32, min: f32, max: f32, dir: f32| {
+            if dir > 0.0 {
+                (max - origin) / dir
+            } else if dir < 0.0 {
+                (min - origin) / dir
+            } else {
+                f32::INFINITY
+            }
+        };
+        to_edge(center.x, rect.min.x, rect.max.x, dir.x).min(to_edge(center.y, rect.min.y, rect.max.y, dir.y))
+    };
+    let edge_point = |t: f32| {
+        let angle = t * std::f32::consts::TAU;
+        let dir = Vec2::new(angle.sin(), -angle.cos());
+        center + dir * ray_length(dir)
+    };
+
+    let mut mesh = Mesh::default();
+    for i in 0..CONIC_SEGMENTS {
+        let t0 = i as f32 / CONIC_SEGMENTS as f32;
+        let t1 = (i + 1) as f32 / CONIC_SEGMENTS as f32;
+        // Every wedge gets its own center vertex, colored by the middle of the wedge, to avoid smearing
+        let first = mesh.vertices.len() as u32;
+        mesh.colored_vertex(center, sample_color_stops(stops, 0.5 * (t0 + t1)));
+        mesh.colored_vertex(edge_point(t0), sample_color_stops(stops, t0));
+        mesh.colored_vertex(edge_point(t1), sample_color_stops(stops, t1));
+        mesh.add_triangle(first, first + 1, first + 2);
+    }
+    painter.add(mesh);
+}
+
+/// Samples a looping list of color stops at `t`, blending in linear light.
+/// Positions before the first or after the last stop blend between the last and the first stop.
+fn sample_color_stops(stops: &[(f32, Color32)], t: f32) -> Color32 {
+    let (Some(&(first_pos, first_color)), Some(&(last_pos, last_color))) = (stops.first(), stops.last()) else {
+        return Color32::TRANSPARENT;
+    };
+    let blend = |from: Color32, to: Color32, start: f32, end: f32, t: f32| {
+        let span = end - start;
+        let factor = if span.abs() < f32::EPSILON { 0.0 } else { ((t - start) / span).clamp(0.0, 1.0) };
+        Color32::from(lerp(Rgba::from(from)..=Rgba::from(to), factor))
+    };
+
+    if let Some(pair) = stops.windows(2).find(|pair| pair[0].0 <= t && t < pair[1].0) {
+        return blend(pair[0].1, pair[1].1, pair[0].0, pair[1].0, t);
+    }
+    // Wrap around from the last stop back to the first one
+    let t = if t < first_pos { t + 1.0 } else { t };
+    blend(last_color, first_color, last_pos, first_pos + 1.0, t)
+}
+
+/// Filter state for the project highlights, rendered with [`ProjectGallery::show`].
+/// Persisted with the app state so filters survive a reload.
+#[derive(Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct ProjectGallery {
+    /// Whitespace separated search tokens, matched case-insensitively against title, tags and description.
+    pub query: String,
+    /// Tags a project must all carry to be shown.
+    pub active_tags: BTreeSet<String>,
+    /// Slug of the project open in the detail overlay. Mirrors the `#/projects/<slug>` hash when there is a browser location.
+    #[serde(skip)]
+    pub selected: Option<String>,
+    /// Zero-based page of the filtered projects being shown.
+    #[serde(skip)]
+    pub page: usize,
+    /// Lays out every match on a single page without hover effects, for printing.
+    #[serde(skip)]
+    pub print_layout: bool,
+    /// The most columns of cards for the current screen size. Fewer are used when the cards wouldn't fit.
+    #[serde(skip)]
+    pub max_columns: usize,
+    /// The layout tier the cards are shown at: framed surfaces on wider screens, a flat divided list on phones.
+    #[serde(skip)]
+    pub screen_size: ScreenSize,
+    #[serde(skip)]
+    document_meta: DocumentMeta,
+}
+
+/// How many project cards [`ProjectGallery::show`] lays out per page.
+const PROJECTS_PER_PAGE: usize = 8;
+/// The narrowest a project card gets before the grid drops a column.
+const MIN_CARD_WIDTH: f32 = 420.0;
+
+impl ProjectGallery {
+    /// Whether the project passes both the tag filters and every token of the search query.
+    /// An empty query and no active tags match everything.
+    pub fn matches(&self, project: &ProjectHighlight) -> bool {
+        let tags_match = self.active_tags.iter().all(|active| {
+            project.tags.iter().any(|tag| tag.name.eq_ignore_ascii_case(active))
+        });
+        let title = project.title.to_lowercase();
+        let description = project.description.to_lowercase();
+        let query = self.query.to_lowercase();
+        let query_match = query.split_whitespace().all(|token| {
+            title.contains(token)
+                || description.contains(token)
+                || project.tags.iter().any(|tag| tag.name.to_lowercase().contains(token))
+        });
+        tags_match && query_match
+    }
+
+    /// How many columns of cards fit in `available_width`, between one and [`Self::max_columns`].
+    pub fn column_count(&self, available_width: f32) -> usize {
+        let fitting = (available_width / MIN_CARD_WIDTH).floor() as usize;
+        fitting.clamp(1, self.max_columns.max(1))
+    }
+
+    /// Replaces the search query, returning to the first page of results.
+    pub fn set_query(&mut self, query: String) {
+        if self.query != query {
+            self.query = query;
+            self.page = 0;
+        }
+    }
+
+    /// Drops the search query and every active tag, returning to the first page of all projects.
+    pub fn clear_filters(&mut self) {
+        self.query.clear();
+        self.active_tags.clear();
+        self.page = 0;
+    }
+
+    /// Renders the tag filter row followed by the current page of projects matching the filters.
+    /// Only the projects on the current page are laid out, so thumbnails on other pages aren't fetched.
+    pub fn show(&mut self, ui: &mut Ui, ctx: &egui::Context, root_url: &String, projects: &mut [ProjectHighlight]) {
+        // Collect every tag once, in the order they first appear, with how many projects carry it
+        let mut all_tags: Vec<(String, usize)> = Vec::new();
+        for tag in projects.iter().flat_map(|project| &project.tags) {
+            match all_tags.iter_mut().find(|(known, _)| known.eq_ignore_ascii_case(&tag.name)) {
+                Some((_, count)) => *count += 1,
+                None => all_tags.push((tag.name.clone(), 1)),
+            }
+        }
+        ui.horizontal_wrapped(|ui| {
+            for (tag, count) in all_tags {
+                let active = self.active_tags.contains(&tag);
+                let text = egui::RichText::new(&tag).font(FontId::new(14.0, egui::FontFamily::Proportional));
+                if ui.add(ButtonWithUnderline::selectable(active, text).badge(count.to_string())).clicked() {
+                    if active {
+                        self.active_tags.remove(&tag);
+                    } else {
+                        self.active_tags.insert(tag);
+                    }
+                    self.page = 0;
+                }
+            }
+        });
+        ui.add_space(8.0);
+
+        // The hash is the source of truth, so the browser's Back button closes the overlay
+        if let Some(hash) = current_hash() {
+            self.selected = match Route::from_hash(&hash) {
+                Route::Project(slug) => Some(slug),
+                _ => None,
+            };
+        }
+
+        let has_projects = !projects.is_empty();
+        let mut visible_projects: Vec<&mut ProjectHighlight> = projects.iter_mut()
+            .filter(|project| self.matches(project))
+            .collect();
+        // Pinned projects lead, otherwise keeping the order from `data.toml`
+        visible_projects.sort_by_key(|project| !project.featured);
+        if visible_projects.is_empty() && has_projects && no_matches_notice(ui) {
+            self.clear_filters();
+            ui.ctx().request_repaint();
+        }
+        let per_page = if self.print_layout { visible_projects.len().max(1) } else { PROJECTS_PER_PAGE };
+        let page_count = visible_projects.len().div_ceil(per_page).max(1);
+        let page = self.page.min(page_count - 1);
+        if !self.print_layout {
+            self.page = page;
+        }
+        let page_start = page * per_page;
+        let page_end = (page_start + per_page).min(visible_projects.len());
+        let columns = self.column_count(ui.available_width());
+        // Featured projects get a full-width row each, the rest share rows
+        let page_projects = &mut visible_projects[page_start..page_end];
+        let featured_count = page_projects.iter().take_while(|project| project.featured).count();
+        let (featured, others) = page_projects.split_at_mut(featured_count);
+        let row_count = featured_count + others.len().div_ceil(columns);
+        let rows = featured.chunks_mut(1).map(|row| (1, row)).chain(others.chunks_mut(columns).map(|row| (columns, row)));
+        let mut open_request = None;
+        let mut grid: Vec<Vec<ProjectCard>> = Vec::with_capacity(row_count);
+        for (row_idx, (columns, row)) in rows.enumerate() {
+            // Always split into the full column count, so a partial last row keeps its cards' widths and stays left-aligned
+            let mut cards = Vec::with_capacity(row.len());
+            ui.columns(columns, |column_uis| {
+                for (ui, project) in column_uis.iter_mut().zip(row.iter_mut()) {
+                    let card = add_highlighted_project(ui, ctx, root_url, project, !self.print_layout, self.screen_size);
+                    if card.open_details {
+                        open_request = Some(project.slug.clone());
+                    }
+                    cards.push(card);
+                }
+            });
+            grid.push(cards);
+            ui.add_space(8.0);
+            // Framed cards already stand apart, the flat list needs a divider between rows
+            if row_idx + 1 < row_count && self.screen_size == ScreenSize::Small {
+                ui.separator();
+                ui.add_space(8.0);
+            }
+        }
+        navigate_cards(ctx, &grid);
+        if page_count > 1 {
+            ui.add_space(8.0);
+            self.page_controls(ui, page_count);
+        }
+        if let Some(slug) = open_request {
+            track(ctx, "project_open", &[("slug", &slug)]);
+            set_location_hash(&Route::Project(slug.clone()).to_hash());
+            self.selected = Some(slug);
+        }
+
+        let selected = self.selected.as_ref().and_then(|slug| projects.iter().find(|project| &project.slug == slug));
+        self.document_meta.sync(selected);
+        if let Some(project) = selected {
+            if show_project_details(ctx, root_url, project) {
+                self.close_project();
+            }
+        }
+    }
+
+    /// Closes the project detail overlay (if open), returning the route to the home page.
+    pub fn close_project(&mut self) {
+        if self.selected.take().is_some() {
+            // Replace rather than push, so Back doesn't reopen the overlay
+            replace_location_hash(&Route::Home.to_hash());
+        }
+    }
+
+    /// Renders previous / next buttons around a selectable button per page.
+    fn page_controls(&mut self, ui: &mut Ui, page_count: usize) {
+        ui.horizontal(|ui| {
+            let font = FontId::new(14.0, egui::FontFamily::Proportional);
+            if ui.add(ButtonWithUnderline::new(egui::RichText::new("‹ Prev").font(font.clone())).frame(false).enabled(self.page > 0)).clicked() {
+                self.page -= 1;
+            }
+            for page in 0..page_count {
+                let text = egui::RichText::new((page + 1).to_string()).font(font.clone());
+                if ui.add(ButtonWithUnderline::selectable(page == self.page, text)).clicked() {
+                    self.page = page;
+                }
+            }
+            if ui.add(ButtonWithUnderline::new(egui::RichText::new("Next ›").font(font)).frame(false).enabled(self.page + 1 < page_count)).clicked() {
+                self.page += 1;
+            }
+        });
+    }
+}
+
+/// Explains that the filters hide every project, offering to clear them. Returns whether "Clear filters" was clicked.
+fn no_matches_notice(ui: &mut Ui) -> bool {
+    ui.vertical_centered(|ui| {
+        ui.add_space(24.0);
+        ui.label(egui::RichText::new("No projects match your filters").weak());
+        ui.add_space(4.0);
+        let clear = ButtonWithUnderline::new(egui::RichText::new("Clear filters").font(FontId::new(14.0, egui::FontFamily::Proportional))).frame(false);
+        let clicked = ui.add(clear).clicked();
+        ui.add_space(24.0);
+        clicked
+    }).inner
+}
+
+/// Moves keyboard focus between the cards with the arrow keys while one of them has focus.
+/// Left and right step through the cards in order, up and down keep to the same column where the next row has one.
+/// Enter needs no handling, as it clicks the focused widget which opens the project.
+/// - `ctx`: The egui context.
+/// - `grid`: The cards on the current page, row by row.
+fn navigate_cards(ctx: &egui::Context, grid: &[Vec<ProjectCard>]) {
+    let position = grid.iter().enumerate().find_map(|(row, cards)| {
+        cards.iter().position(|card| card.focused.is_some()).map(|col| (row, col))
+    });
+    let Some((row, col)) = position else {
+        return;
+    };
+    let focused = grid[row][col].focused.unwrap_or(grid[row][col].title_id);
+    // Keep egui's own arrow key focus search from competing with the grid's
+    let arrows = egui::EventFilter { horizontal_arrows: true, vertical_arrows: true, ..Default::default() };
+    if ctx.memory(|memory| memory.had_focus_last_frame(focused)) {
+        ctx.memory_mut(|memory| memory.set_focus_lock_filter(focused, arrows));
+    } else {
+        // The filter can only be set once the focus has lasted a frame, so make sure that frame comes before any arrow key
+        ctx.request_repaint();
+    }
+
+    let flat: Vec<&ProjectCard> = grid.iter().flatten().collect();
+    let index = grid[..row].iter().map(Vec::len).sum::<usize>() + col;
+    let in_row = |row: usize| grid.get(row).filter(|cards| !cards.is_empty()).map(|cards| &cards[col.min(cards.len() - 1)]);
+    let target = ctx.input(|input| {
+        if input.key_pressed(egui::Key::ArrowLeft) {
+            index.checked_sub(1).map(|index| flat[index])
+        } else if input.key_pressed(egui::Key::ArrowRight) {
+            flat.get(index + 1).copied()
+        } else if input.key_pressed(egui::Key::ArrowUp) {
+            row.checked_sub(1).and_then(in_row)
+        } else if input.key_pressed(egui::Key::ArrowDown) {
+            in_row(row + 1)
+        } else {
+            None
+        }
+    });
+    if let Some(card) = target {
+        ctx.memory_mut(|memory| memory.request_focus(card.title_id));
+    }
+}
+
+/// Mirrors the open project into the browser tab's title and meta description, for tabs and link previews.
+/// The page's own title and description are restored once no project is open.
+#[derive(Default)]
+struct DocumentMeta {
+    synced_slug: Option<String>, // The project the document currently describes
+    defaults: Option<(String, Option<String>)>, // The page's own title and description, captured before the first change
+}
+
+impl DocumentMeta {
+    const SITE_NAME: &str = "ZeroUni";
+    const DESCRIPTION_LEN: usize = 160; // Roughly what link previews show before truncating
+
+    /// Updates the document when the open project changed since the last call.
+    /// Does nothing without a browser document (e.g. native builds).
+    fn sync(&mut self, project: Option<&ProjectHighlight>) {
+        let slug = project.map(|project| &project.slug);
+        if self.synced_slug.as_ref() == slug {
+            return;
+        }
+        self.synced_slug = slug.cloned();
+        let Some(document) = window().and_then(|window| window.document()) else {
+            return;
+        };
+        let description_meta = document.query_selector("meta[name=\"description\"]").ok().flatten();
+        let (default_title, default_description) = self.defaults.get_or_insert_with(|| {
+            (document.title(), description_meta.as_ref().and_then(|meta| meta.get_attribute("content")))
+        });
+
+        let (title, description) = if let Some(project) = project {
+            let title = format!("{} — Project: {}", Self::SITE_NAME, project.title);
+            (title, Some(preview_text(&project.description, Self::DESCRIPTION_LEN)))
+        } else {
+            (default_title.clone(), default_description.clone())
+        };
+        document.set_title(&title);
+        if let (Some(meta), Some(description)) = (&description_meta, description) {
+            if let Err(error) = meta.set_attribute("content", &description) {
+                log::warn!("Failed to set the page description: {error:?}");
+            }
+        }
+    }
+}
+
+/// Mutates the given ui to add a block of text cut to a preview of `preview_chars` characters, with a
+/// "Read more" / "Show less" toggle that grows and shrinks it. Short texts are shown in full without a toggle.
+/// Whether it is expanded is remembered for the session only.
+/// - `ui`: The UI context to draw on.
+/// - `id_salt`: Distinguishes this block's expanded state from others.
+/// - `text`: The full text.
+/// - `preview_chars`: How many characters the collapsed preview shows, see [`preview_text`].
+/// - `font_size`: The font size of the text.
+pub fn collapsible_text(ui: &mut Ui, id_salt: impl std::hash::Hash, text: &str, preview_chars: usize, font_size: f32) {
+    let text = text.trim();
+    let preview = preview_text(text, preview_chars);
+    let font_id = FontId::new(font_size, egui::FontFamily::Proportional);
+    let text_color = ui.visuals().text_color();
+    let width = ui.available_width();
+    let full_galley = ui.fonts(|fonts| fonts.layout(text.to_owned(), font_id.clone(), text_color, width));
+    if preview == preview_text(text, usize::MAX) {
+        ui.label(egui::RichText::new(text).font(font_id));
+        return;
+    }
+
+    let id = ui.make_persistent_id(id_salt);
+    let mut expanded = ui.data(|data| data.get_temp::<bool>(id)).unwrap_or(false);
+    let preview_galley = ui.fonts(|fonts| fonts.layout(preview.clone(), font_id, text_color, width));
+    let target_height = if expanded { full_galley.size().y } else { preview_galley.size().y };
+    let height = animate_value(ui.ctx(), id.with("height"), target_height, ui.style().animation_time);
+    // The full text is revealed (or hidden) under a clip while growing, the preview only shows once fully collapsed
+    let settled_collapsed = !expanded && (height - preview_galley.size().y).abs() < 0.5;
+    let (galley, visible_text) = if settled_collapsed { (preview_galley, preview.as_str()) } else { (full_galley, text) };
+
+    let (rect, response) = ui.allocate_exact_size(vec2(width, height), Sense::hover());
+    if ui.is_rect_visible(rect) {
+        ui.painter_at(rect).galley(rect.min, galley, text_color);
+    }
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, true, visible_text));
+
+    let toggle = if expanded { "Show less" } else { "Read more" };
+    if ui.add(ButtonWithUnderline::new(toggle).frame(false)).clicked() {
+        expanded = !expanded;
+        ui.data_mut(|data| data.insert_temp(id, expanded));
+    }
+}
+
+/// Collapses whitespace and shortens the text to at most `max_chars` characters, ending in `…` when cut.
+fn preview_text(text: &str, max_chars: usize) -> String {
+    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= max_chars {
+        return collapsed;
+    }
+    let mut preview: String = collapsed.chars().take(max_chars.saturating_sub(1)).collect();
+    preview.truncate(preview.trim_end().len());
+    preview.push('…');
+    preview
+}
+
+/// Shows a project's full details in an overlay above a dimmed backdrop.
+/// Returns `true` when the overlay should close (X button, backdrop click or Escape).
+/// - `ctx`: The egui context to draw on.
+/// - `root_url`: The root url used to resolve the project's images.
+/// - `project`: The project to show.
+fn show_project_details(ctx: &egui::Context, root_url: &str, project: &ProjectHighlight) -> bool {
+    let mut close = ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::Escape));
+    let screen_rect = ctx.screen_rect();
+
+    egui::Area::new(egui::Id::new("project_details_backdrop"))
+        .order(egui::Order::Foreground)
+        .fixed_pos(screen_rect.min)
+        .show(ctx, |ui| {
+            let backdrop = ui.allocate_rect(screen_rect, Sense::click());
+            ui.painter().rect_filled(screen_rect, 0.0, Color32::from_black_alpha(160));
+            close |= backdrop.clicked();
+        });
+
+    let max_size = (screen_rect.size() - vec2(32.0, 32.0)).min(vec2(720.0, 800.0));
+    egui::Area::new(egui::Id::new("project_details").with(&project.slug))
+        .order(egui::Order::Foreground)
+        .anchor(egui::Align2::CENTER_CENTER, Vec2::ZERO)
+        .show(ctx, |ui| {
+            // Keep the overlay above its backdrop
+            ctx.move_to_top(ui.layer_id());
+            Frame::popup(ui.style()).inner_margin(Margin::same(16)).show(ui, |ui| {
+                ui.set_max_size(max_size);
+                ui.horizontal(|ui| {
+                    ui.heading(&project.title);
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        close |= ui.add(ButtonWithUnderline::new("✖").frame(false)).on_hover_text("Close").clicked();
+                    });
+                });
+                ui.separator();
+                egui::ScrollArea::vertical().max_height(max_size.y - 64.0).show(ui, |ui| {
+                    let image = match project.thumbnail {
+                        Some(thumbnail) => Image::new(thumbnail),
+                        None => Image::new(resolve_url(root_url, project.thumbnail_path())),
+                    };
+                    ui.add(image.maintain_aspect_ratio(true).max_size(vec2(max_size.x, 360.0)).corner_radius(4.0));
+                    ui.add_space(8.0);
+                    ui.horizontal_wrapped(|ui| {
+                        for tag in &project.tags {
+                            tag_chip(ui, tag, root_url, 12.0);
+                        }
+                    });
+                    ui.add_space(8.0);
+                    markdown_label(ui, &project.description);
+                    ui.add_space(8.0);
+                    ui.horizontal_wrapped(|ui| {
+                        if ui.add(ButtonWithUnderline::new("Visit ↗").frame(false)).on_hover_text(&project.external_link).clicked() {
+                            track(ui.ctx(), "link_click", &[("url", &project.external_link)]);
+                            open_link(ui.ctx(), &project.external_link, link_target(ui.ctx()));
+                        }
+                        project_links(ui, project, 14.0);
+                    });
+                });
+            });
+        });
+    close
+}
+
+/// Mutates the given ui to add the project's "Live demo" and "Source" links as [`socials`], skipping any it doesn't have.
+/// - `ui`: The UI context to draw on.
+/// - `project`: The project whose links to show.
+/// - `font_size`: The font size of the links.
+fn project_links(ui: &mut Ui, project: &ProjectHighlight, font_size: f32) {
+    const GLOBE: &str = "🌐";
+    const GITHUB: &str = "\u{e624}"; // GitHub logo in egui's bundled icon font
+    let links = [(GLOBE, "Live demo", &project.live_url), (GITHUB, "Source", &project.source_url)];
+    if links.iter().all(|(_, _, url)| url.is_none()) {
+        return;
+    }
+    ui.horizontal_wrapped(|ui| {
+        for (icon, label, url) in links {
+            if let Some(url) = url {
+                socials(ui, &format!("{icon} {label}"), url, &None, font_size);
+            }
+        }
+    });
+}
+
+/// The centered part of an image, in uv coordinates, that covers `target_size` without stretching, like CSS `object-fit: cover`.
+/// - `image_size`: The size of the whole image.
+/// - `target_size`: The size of the area the image is shown in.
+pub fn cover_uv(image_size: Vec2, target_size: Vec2) -> Rect {
+    let full = Rect::from_min_max(Pos2::ZERO, pos2(1.0, 1.0));
+    if image_size.min_elem() <= 0.0 || target_size.min_elem() <= 0.0 {
+        return full;
+    }
+    let image_aspect = image_size.x / image_size.y;
+    let target_aspect = target_size.x / target_size.y;
+    // Keep the whole of the tighter axis and crop the other evenly on both sides
+    let visible = if image_aspect > target_aspect {
+        vec2(target_aspect / image_aspect, 1.0)
+    } else {
+        vec2(1.0, image_aspect / target_aspect)
+    };
+    Rect::from_center_size(full.center(), visible)
+}
+
+/// Paints a loading placeholder with a shine sweeping across it, repainting until replaced.
+/// - `ui`: The UI context to draw on.
+/// - `rect`: The area the content will occupy once loaded.
+fn paint_skeleton(ui: &Ui, rect: Rect) {
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 2.0, ui.visuals().widgets.noninteractive.weak_bg_fill);
+
+    // Keep the placeholder static when animations are disabled (e.g. reduced motion)
+    if ui.style().animation_time <= 0.0 {
+        return;
+    }
+
+    // Sweep a soft band from left to right once every 1.2 seconds
+    let phase = (now(ui.ctx()) % 1.2 / 1.2) as f32;
+    let band_width = rect.width() * 0.5;
+    let band_left = lerp(rect.left() - band_width..=rect.right(), phase);
+    let band = Rect::from_min_size(pos2(band_left, rect.top()), vec2(band_width, rect.height()));
+    let (leading, trailing) = band.split_left_right_at_fraction(0.5);
+    let shine = Color32::from_white_alpha(24);
+    paint_angular_gradient(&painter, leading, Color32::TRANSPARENT, shine, std::f32::consts::FRAC_PI_2, Vec2::splat(1.0));
+    paint_angular_gradient(&painter, trailing, shine, Color32::TRANSPARENT, std::f32::consts::FRAC_PI_2, Vec2::splat(1.0));
+    ui.ctx().request_repaint();
+}
+
+/// Paints a placeholder for an image that failed to load, labelled so the layout keeps its meaning.
+/// - `ui`: The UI context to draw on.
+/// - `rect`: The area the image would have occupied.
+/// - `title`: The title to show in place of the image.
+fn paint_broken_image(ui: &Ui, rect: Rect, title: &str) {
+    let visuals = ui.visuals();
+    let painter = ui.painter_at(rect);
+    painter.rect(rect.shrink(1.0), 2.0, visuals.widgets.noninteractive.weak_bg_fill, visuals.widgets.noninteractive.bg_stroke, egui::StrokeKind::Inside);
+    painter.text(rect.center() - vec2(0.0, 10.0), egui::Align2::CENTER_CENTER, "🖼", FontId::proportional(28.0), visuals.weak_text_color());
+    painter.text(rect.center() + vec2(0.0, 20.0), egui::Align2::CENTER_CENTER, title, FontId::proportional(12.0), visuals.weak_text_color());
+}
+
+/// What [`add_highlighted_project`] reports about a card.
+pub struct ProjectCard {
+    pub open_details: bool, // The card asked for the project's details to be opened
+    pub title_id: egui::Id, // The focus target when moving between cards with the arrow keys
+    pub focused: Option<egui::Id>, // The card's widget with keyboard focus, if any
+}
+
+/// Mutates the given ui to display a project card with its thumbnail, tags and description. Featured projects are outlined.
+/// Returns whether the details should open and which of the card's widgets has keyboard focus, see [`ProjectCard`].
+/// - `ui`: The UI context to draw on.
+/// - `ctx`: The egui context, used to load the thumbnail.
+/// - `root_url`: The root url used to resolve the project's images.
+/// - `project`: The project to show.
+/// - `hover_lift`: Whether the card lifts while hovered.
+/// - `screen_size`: The layout tier. Cards sit on a framed, shadowed surface except on [`ScreenSize::Small`], where they are drawn flat.
+pub fn add_highlighted_project(ui: &mut egui::Ui, ctx: &egui::Context, root_url: &String, project: &mut ProjectHighlight, hover_lift: bool, screen_size: ScreenSize) -> ProjectCard {
+    // Keyed by the slug, so per-card state (the open image, the lift) stays with its project
+    ui.push_id(project.slug.clone(), |ui| {
+        let mut open_details = false;
+        let mut title_id = egui::Id::NULL;
+        let mut focused = None;
+        // Reserve a slot below the card for the hover lift, filled in once the card's size is known
+        let backdrop = ui.painter().add(egui::Shape::Noop);
+        let card_margin = Margin::symmetric(8, 4);
+        let mut card_style = CardStyle { outer_margin: card_margin, ..CardStyle::group(ui.style()) };
+        if screen_size == ScreenSize::Small {
+            card_style.stroke = Stroke::NONE;
+            card_style.corner_radius = CornerRadius::ZERO;
+        } else {
+            // The inactive widgets' weak fill is the palette's surface color in both themes
+            card_style.fill = ui.visuals().widgets.inactive.weak_bg_fill;
+            card_style.shadow = egui::Shadow {
+                offset: [0, 2],
+                blur: 8,
+                spread: 0,
+                color: Color32::from_black_alpha(40),
+            };
+        }
+        if project.featured {
+            card_style.stroke = Stroke::new(1.5, ui.visuals().selection.bg_fill);
+        }
+        let card_response = card(ui, card_style, |ui| {
+            ui.with_layout(egui::Layout::top_down(egui::Align::LEFT), |ui| {
+                let mut img_rect = Rect::NOTHING;
+                let main_response = ui.horizontal(|ui| {
+                    match project.get_set_thumbnail(root_url, ctx) {
+                        ThumbnailState::Ready(thumbnail) => {
+                            const THUMBNAIL_SIZE: Vec2 = vec2(128.0, 128.0);
+                            const THUMBNAIL_RADIUS: f32 = 6.0;
+                            // Crop rather than stretch, so any aspect ratio fills the square
+                            let image = Image::new(thumbnail).uv(cover_uv(thumbnail.size, THUMBNAIL_SIZE)).fit_to_exact_size(THUMBNAIL_SIZE).corner_radius(THUMBNAIL_RADIUS);
+                            let img_response = ui.add(Button::image(image));
+                            let border = Rect::from_center_size(img_response.rect.center(), THUMBNAIL_SIZE);
+                            if let Some(stroke) = thumbnail_border(ui.ctx()) {
+                                ui.painter().rect_stroke(border, THUMBNAIL_RADIUS, stroke, egui::StrokeKind::Inside);
+                            }
+                            paint_focus_ring(ui, &img_response);
+                            if img_response.has_focus() {
+                                focused = Some(img_response.id);
+                            }
+                            img_rect = img_response.rect;
+                            open_details |= img_response.clicked();
+                        }
+                        ThumbnailState::Loading => {
+                            let (rect, _) = ui.allocate_exact_size(Vec2::new(128.0, 128.0) + 2.0 * ui.spacing().button_padding, Sense::hover());
+                            paint_skeleton(ui, rect);
+                        }
+                        ThumbnailState::Failed => {
+                            let (rect, _) = ui.allocate_exact_size(Vec2::new(128.0, 128.0) + 2.0 * ui.spacing().button_padding, Sense::hover());
+                            paint_broken_image(ui, rect, &project.title);
+                        }
+                    }
+                    ui.vertical(|ui| {
+                        ui.style_mut().wrap_mode = Some(TextWrapMode::Wrap);
+                        let title = ui.add(egui::Label::new(egui::RichText::new(&project.title).heading()).sense(Sense::click()))
+                            .on_hover_cursor(egui::CursorIcon::PointingHand);
+                        paint_focus_ring(ui, &title);
+                        open_details |= title.clicked();
+                        title_id = title.id;
+                        if title.has_focus() {
+                            focused = Some(title.id);
+                        }
+                        // Once "+N" is clicked the card grows to list every tag instead
+                        let tags_expanded_id = ui.id().with("tags_expanded");
+                        if ui.data(|data| data.get_temp::<bool>(tags_expanded_id)).unwrap_or(false) {
+                            ui.horizontal_wrapped(|ui| tag_chips(ui, &project.tags, root_url, usize::MAX));
+                        } else {
+                            let fade_color = ui.visuals().extreme_bg_color;
+                            let expand = scroll_row_with_fades(ui, ("project_tags", &project.slug), fade_color, |ui| {
+                                tag_chips(ui, &project.tags, root_url, max_visible_tags(screen_size))
+                            }).inner;
+                            if expand {
+                                ui.data_mut(|data| data.insert_temp(tags_expanded_id, true));
+                            }
+                        }
+                        markdown_label(ui, &project.description);
+                        project_links(ui, project, 14.0);
+                    });
+                }).response;
+                // If img_rect has been assigned, draw a corner line down from the bottom center of the image
+                if img_rect.ne(&Rect::NOTHING) && project.highlight_imgs.len() > 0 {
+                    let top_color = Color32::from_rgba_unmultiplied(94, 84, 142, 127);
+                    let line_start = img_rect.center_bottom();
+                    let vertical_end = pos2(img_rect.center().x, main_response.rect.bottom() + 66.0);
+                    let horizontal_end = vertical_end + Vec2::new(64.0, 0.0);
+                    ui.painter().line_segment([line_start, vertical_end], (3.0, ui.visuals().extreme_bg_color.blend(top_color)));
+                    ui.painter().line_segment([vertical_end, horizontal_end], (3.0, ui.visuals().extreme_bg_color.blend(top_color)));
+                    ui.painter().line_segment([line_start, vertical_end], (1.0, Color32::from_gray(40)));
+                    ui.painter().line_segment([vertical_end, horizontal_end], (1.0, Color32::from_gray(40)));
+                    ui.add_space(12.0);
+                    // Wrap rather than overflow into the neighbouring card in the grid
+                    ui.horizontal_wrapped(|ui| {
+                        ui.add_space(144.0);
+                        ui.spacing_mut().item_spacing = vec2(12.0, 0.0);
+                        let persistent_id = ui.make_persistent_id("highlight_images");
+                        let some_modal_shown = ui.data(|data| data.get_temp(persistent_id)).unwrap_or(project.highlight_imgs.len());
+                        if some_modal_shown < project.highlight_imgs.len() {
+                            show_modal(ctx, ui, project.highlight_imgs.len(), some_modal_shown, &project.title, &(root_url.to_owned() + &project.highlight_imgs[some_modal_shown]), persistent_id);
+                        }
+                        for (idx, img_path) in project.highlight_imgs.iter().enumerate() {
+                            let image = Image::new(root_url.to_owned() + img_path).maintain_aspect_ratio(true).max_size(vec2(500.0, 112.0)).corner_radius(2.0);
+                            let size = image.calc_size(vec2(500.0, 500.0), None);
+                            let (image_rect, image_response) = ui.allocate_at_least(size, Sense::click());
+                            let hovered = image_response.hovered();
+                            // Image::new(root_url.to_owned() + img_path).fit_to_exact_size(Vec2::new(112.0 + 24.0 * f32::from(hovered), 112.0 + 24.0 * f32::from(hovered))).corner_radius(2.0)
+                            image.max_height(112.0 + 24.0 * f32::from(hovered)).paint_at(ui, image_rect.expand(12.0 * f32::from(hovered)).translate(vec2(8.0 * f32::from(hovered), 0.0)));
+                            paint_focus_ring(ui, &image_response);
+                            if hovered {ui.add_space(16.0);}
+                            if image_response.clicked() {
+                                ui.data_mut(|data| {
+                                    data.insert_persisted(persistent_id, idx);
+                                });
+                            }
+                            let should_show = ui.data(|data| {
+                                data.get_temp(persistent_id)
+                            }).unwrap_or(project.highlight_imgs.len()) == idx;
+                        }
+                    });
+                }
+            });
+        });
+
+        if hover_lift {
+            paint_card_lift(ui, backdrop, card_response.rect - card_margin, ui.id().with("lift"));
+        }
+        ProjectCard { open_details, title_id, focused }
+    }).inner
+}
+
+/// Lifts a card while it is hovered by growing a backdrop behind it and casting a shadow.
+/// The card's contents (and click targets) stay put; only the backdrop grows, and not at all when animations are disabled.
+/// - `ui`: The UI context to draw on.
+/// - `backdrop`: A shape slot reserved before the card was drawn, so the lift sits below it.
+/// - `card_rect`: The visual bounds of the card.
+/// - `id`: A stable id for the card's lift animation.
+fn paint_card_lift(ui: &Ui, backdrop: egui::layers::ShapeIdx, card_rect: Rect, id: egui::Id) {
+    const MAX_GROWTH: f32 = 4.0;
+    let animation_time = ui.style().animation_time;
+    let lift = emath::easing::cubic_out(animate_bool(ui.ctx(), id, ui.rect_contains_pointer(card_rect), animation_time));
+    if lift <= 0.0 {
+        return;
+    }
+
+    let growth = if animation_time > 0.0 { lift * MAX_GROWTH } else { 0.0 };
+    let lifted_rect = card_rect.expand(growth);
+    let corner_radius = ui.visuals().widgets.noninteractive.corner_radius;
+    let shadow = egui::Shadow {
+        offset: [0, 4],
+        blur: 12,
+        spread: 0,
+        color: Color32::from_black_alpha(90).gamma_multiply(lift),
+    };
+    ui.painter().set(backdrop, egui::Shape::Vec(vec![
+        shadow.as_shape(lifted_rect, corner_radius).into(),
+        egui::Shape::rect_filled(lifted_rect, corner_radius, ui.visuals().faint_bg_color.gamma_multiply(lift)),
+    ]));
+}
+
+/// A piece of inline Markdown, see [`parse_inline_markdown`].
+#[derive(Debug, PartialEq)]
+enum MarkdownSpan<'s> {
+    Text(&'s str),
+    Bold(&'s str),
+    Link { text: &'s str, url: &'s str },
+}
+
+/// Splits a single line into plain text, `**bold**` and `[text](url)` spans.
+/// Unterminated markers are kept as plain text.
+fn parse_inline_markdown(line: &str) -> Vec<MarkdownSpan<'_>> {
+    let mut spans = Vec::new();
+    let mut rest = line;
+    let mut idx = 0;
+    while idx < rest.len() {
+        let tail = &rest[idx..];
+        // Find a complete span starting at the current position, along with its length in bytes
+        let matched = if let Some(inner) = tail.strip_prefix("**") {
+            inner.find("**").map(|end| (MarkdownSpan::Bold(&inner[..end]), end + 4))
+        } else if let Some(inner) = tail.strip_prefix('[') {
+            inner.find("](").and_then(|text_end| {
+                let after = &inner[text_end + 2..];
+                after.find(')').map(|url_end| (MarkdownSpan::Link { text: &inner[..text_end], url: &after[..url_end] }, text_end + url_end + 4))
+            })
+        } else {
+            None
+        };
+        match matched {
+            Some((span, len)) => {
+                if idx > 0 {
+                    spans.push(MarkdownSpan::Text(&rest[..idx]));
+                }
+                spans.push(span);
+                rest = &rest[idx + len..];
+                idx = 0;
+            }
+            None => idx += tail.chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    if !rest.is_empty() {
+        spans.push(MarkdownSpan::Text(rest));
+    }
+    spans
+}
+
+/// Mutates the given ui to display a minimal subset of Markdown in monospace.
+/// Supports `**bold**`, `[text](url)` links (opened in a new tab) and `- ` bullet lists.
+/// - `ui`: The UI context to draw on.
+/// - `markdown`: The Markdown source to render.
+pub fn markdown_label(ui: &mut Ui, markdown: &str) {
+    for line in markdown.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() {
+            ui.add_space(ui.spacing().item_spacing.y);
+            continue;
+        }
+        let bullet = trimmed.strip_prefix("- ").or_else(|| trimmed.strip_prefix("* "));
+        ui.horizontal_wrapped(|ui| {
+            ui.spacing_mut().item_spacing.x = 0.0;
+            if bullet.is_some() {
+                ui.monospace("  • ");
+            }
+            for span in parse_inline_markdown(bullet.unwrap_or(trimmed)) {
+                match span {
+                    MarkdownSpan::Text(text) => {
+                        ui.label(egui::RichText::new(text).monospace());
+                    }
+                    MarkdownSpan::Bold(text) => {
+                        ui.label(egui::RichText::new(text).monospace().strong());
+                    }
+                    MarkdownSpan::Link { text, url } => {
+                        if ui.link(egui::RichText::new(text).monospace()).on_hover_text(url).clicked() {
+                            track(ui.ctx(), "link_click", &[("url", url)]);
+                            open_link(ui.ctx(), url, link_target(ui.ctx()));
+                        }
+                    }
+                }
+            }
+        });
+    }
+}
+
+/// Mutates the given ui to display a horizontally scrolling row, fading out whichever edge has content clipped past it.
+/// The fades hint that there is more to scroll to where scroll bars are hidden (e.g. on touch screens).
+/// - `ui`: The UI context to draw on.
+/// - `id_salt`: A salt to keep the scroll offset apart from other rows.
+/// - `fade_color`: The color the edges fade into, usually whatever is behind the row.
+/// - `add_contents`: Closure adding the row's contents.
+pub fn scroll_row_with_fades<R>(ui: &mut Ui, id_salt: impl std::hash::Hash, fade_color: Color32, add_contents: impl FnOnce(&mut Ui) -> R) -> egui::scroll_area::ScrollAreaOutput<R> {
+    const FADE_WIDTH: f32 = 24.0;
+    let output = egui::ScrollArea::horizontal()
+        .id_salt(id_salt)
+        .show(ui, |ui| ui.horizontal(add_contents).inner);
+
+    let rect = output.inner_rect;
+    let [left_clipped, right_clipped] = clipped_edges(output.state.offset.x, rect.width(), output.content_size.x);
+    let painter = ui.painter_at(rect);
+    let fade_width = FADE_WIDTH.min(rect.width() / 2.0);
+    // A quarter turn runs the gradient from left to right
+    if left_clipped {
+        let fade = Rect::from_min_size(rect.left_top(), vec2(fade_width, rect.height()));
+        paint_angular_gradient(&painter, fade, fade_color, Color32::TRANSPARENT, std::f32::consts::FRAC_PI_2, Vec2::splat(1.0));
+    }
+    if right_clipped {
+        let fade = Rect::from_min_size(rect.right_top() - vec2(fade_width, 0.0), vec2(fade_width, rect.height()));
+        paint_angular_gradient(&painter, fade, Color32::TRANSPARENT, fade_color, std::f32::consts::FRAC_PI_2, Vec2::splat(1.0));
+    }
+    output
+}
+
+/// Whether a horizontally scrolled row has content hidden past its left and right edges.
+/// - `offset`: How far the row is scrolled.
+/// - `visible_width`: The width of the visible part of the row.
+/// - `content_width`: The width of all of the row's content.
+fn clipped_edges(offset: f32, visible_width: f32, content_width: f32) -> [bool; 2] {
+    // Ignore sub-pixel remainders so the fades don't flicker at the ends
+    [offset > 0.5, offset + visible_width < content_width - 0.5]
+}
+
+/// Mutates the given ui to fade and slide the contents in once they first scroll into view.
+/// Once revealed the contents stay visible, and the animation is skipped entirely when the style's `animation_time` is zero.
+/// - `ui`: The UI context to draw on.
+/// - `id`: A stable id for the section, used to remember whether it has been revealed.
+/// - `add_contents`: Closure adding the section's contents.
+pub fn reveal_on_scroll<R>(ui: &mut Ui, id: egui::Id, add_contents: impl FnOnce(&mut Ui) -> R) -> egui::InnerResponse<R> {
+    const SLIDE_DISTANCE: f32 = 16.0;
+    let animation_time = ui.style().animation_time * 3.0;
+    let revealed = animation_time <= 0.0 || ui.data(|data| data.get_temp::<bool>(id).unwrap_or(false));
+    let progress = if animation_time <= 0.0 {
+        1.0
+    } else {
+        emath::easing::cubic_out(animate_bool(ui.ctx(), id, revealed, animation_time))
+    };
+
+    let layer_id = ui.layer_id();
+    let start = ui.ctx().graphics_mut(|graphics| graphics.entry(layer_id).next_idx());
+    let response = ui.scope(|ui| {
+        ui.multiply_opacity(progress);
+        add_contents(ui)
+    });
+
+    // Offset the painted shapes (not the layout) so the slide doesn't shift neighbouring content
+    if progress < 1.0 {
+        let offset = emath::TSTransform::from_translation(vec2(0.0, (1.0 - progress) * SLIDE_DISTANCE));
+        ui.ctx().graphics_mut(|graphics| {
+            let list = graphics.entry(layer_id);
+            let end = list.next_idx();
+            list.transform_range(start, end, offset);
+        });
+    }
+
+    if !revealed && response.response.rect.intersects(ui.clip_rect()) {
+        ui.data_mut(|data| data.insert_temp(id, true));
+        ui.ctx().request_repaint();
+    }
+    response
+}
+
+/// A transient message shown by [`Toasts`].
+struct Toast {
+    id: egui::Id,
+    message: String,
+    expires_at: f64, // In seconds of the installed clock, see [`now`]
+}
+
+/// Short-lived notifications (e.g. "Copied to clipboard") stacked at the bottom center of the screen.
+#[derive(Default)]
+pub struct Toasts {
+    toasts: Vec<Toast>,
+    pending: Vec<(String, f64)>, // Pushed since the last `show`, which knows the current time
+    next_id: u64,
+}
+
+impl Toasts {
+    /// Queues a toast to be shown from the next frame.
+    /// - `message`: The text of the toast.
+    /// - `duration`: How long the toast stays up, in seconds.
+    pub fn push(&mut self, message: impl Into<String>, duration: f64) {
+        self.pending.push((message.into(), duration));
+    }
+
+    /// Renders the active toasts, fading them in and out and dropping them once they have expired.
+    /// Call once per frame, after the rest of the UI.
+    pub fn show(&mut self, ctx: &egui::Context) {
+        let now = now(ctx);
+        for (message, duration) in self.pending.drain(..) {
+            self.toasts.push(Toast {
+                id: egui::Id::new("toast").with(self.next_id),
+                message,
+                expires_at: now + duration,
+            });
+            self.next_id += 1;
+        }
+
+        let animation_time = ctx.style().animation_time;
+        let mut offset = -24.0;
+        self.toasts.retain(|toast| {
+            let active = now < toast.expires_at;
+            let opacity = animate_bool(ctx, toast.id, active, animation_time);
+            if !active && opacity <= 0.0 {
+                return false;
+            }
+            if active {
+                ctx.request_repaint_after(std::time::Duration::from_secs_f64(toast.expires_at - now));
+            }
+            let response = egui::Area::new(toast.id)
+                .order(egui::Order::Tooltip)
+                .anchor(egui::Align2::CENTER_BOTTOM, vec2(0.0, offset))
+                .interactable(false)
+                .show(ctx, |ui| {
+                    ui.multiply_opacity(opacity);
+                    Frame::popup(ui.style()).inner_margin(Margin::symmetric(12, 8)).show(ui, |ui| {
+                        ui.label(&toast.message);
+                    });
+                }).response;
+            offset -= response.rect.height() + 8.0;
+            true
+        });
+    }
+}
+
+/// Submission state of a [`contact_form`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum SubmitStatus {
+    #[default]
+    Idle,
+    Submitting,
+    Success,
+    Error(String),
+}
+
+/// State of a [`contact_form`], kept across frames.
+#[derive(Default)]
+pub struct FormState {
+    pub name: String,
+    pub email: String,
+    pub message: String,
+    validation_error: Option<&'static str>, // Set when a submit is attempted with invalid fields
+    status: Arc<Mutex<SubmitStatus>>, // Shared with the in-flight request callback
+    announced: SubmitStatus, // Last status a toast was pushed for
+}
+
+impl FormState {
+    /// Returns the first problem with the current fields, if any.
+    pub fn validate(&self) -> Option<&'static str> {
+        if self.name.trim().is_empty() {
+            Some("Please enter your name.")
+        } else if !looks_like_email(self.email.trim()) {
+            Some("Please enter a valid email address.")
+        } else if self.message.trim().is_empty() {
+            Some("Please enter a message.")
+        } else {
+            None
+        }
+    }
+
+    pub fn status(&self) -> SubmitStatus {
+        self.status.lock().map(|status| status.clone()).unwrap_or_default()
+    }
+
+    fn set_status(&self, status: SubmitStatus) {
+        if let Ok(mut current) = self.status.lock() {
+            *current = status;
+        }
+    }
+
+    /// The fields serialized as a JSON object.
+    fn to_json(&self) -> String {
+        format!(
+            r#"{{"name":"{}","email":"{}","message":"{}"}}"#,
+            json_escape(self.name.trim()),
+            json_escape(self.email.trim()),
+            json_escape(self.message.trim()),
+        )
+    }
+}
+
+/// A loose email shape check: something before a single `@`, and a dotted domain after it.
+fn looks_like_email(email: &str) -> bool {
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !domain.contains('@')
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !email.contains(char::is_whitespace)
+}
+
+/// Escapes a string for use inside a JSON string literal.
+pub fn json_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Mutates the given ui to add a "Share" button that opens the device's share sheet through the Web Share API.
+/// Where the API is missing (most desktop browsers and native builds) the url is copied to the clipboard instead.
+/// - `ui`: The UI context to draw on.
+/// - `title`: The title offered alongside the url, e.g. the project's name.
+/// - `url`: The url to share, e.g. the current route's.
+/// - `font_size`: The font size of the button's text.
+/// - `toasts`: Notified when the url was copied instead.
+pub fn share_button(ui: &mut Ui, title: &str, url: &str, font_size: f32, toasts: &mut Toasts) -> Response {
+    let text = egui::RichText::new("Share").font(FontId::new(font_size, egui::FontFamily::Proportional));
+    let response = ui.add(ButtonWithUnderline::new(text).frame(false).inset([8.0, 8.0])).on_hover_text(url);
+    if response.clicked() {
+        track(ui.ctx(), "share", &[("url", url)]);
+        if !native_share(title, url) {
+            ui.ctx().copy_text(url.to_owned());
+            toasts.push("Link copied to clipboard", 2.0);
+        }
+    }
+    response
+}
+
+/// Opens the share sheet for the url, returning `false` when the browser has no Web Share API.
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+fn native_share(title: &str, url: &str) -> bool {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let Some(navigator) = window().map(|window| window.navigator()) else {
+            return false;
+        };
+        // `navigator.share` is missing rather than failing where unsupported
+        if !web_sys::js_sys::Reflect::has(&navigator, &"share".into()).unwrap_or(false) {
+            return false;
+        }
+        let data = web_sys::ShareData::new();
+        data.set_title(title);
+        data.set_url(url);
+        let promise = navigator.share_with_data(&data);
+        wasm_bindgen_futures::spawn_local(async move {
+            // Rejected when the sheet is dismissed, which needs no handling
+            if let Err(error) = wasm_bindgen_futures::JsFuture::from(promise).await {
+                log::debug!("Share did not complete: {error:?}");
+            }
+        });
+        true
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        false
+    }
+}
+
+/// Mutates the given ui to display a contact form that posts its fields as JSON.
+/// - `ui`: The UI context to draw on.
+/// - `state`: The form fields and submission state.
+/// - `endpoint`: The URL to `POST` the form to.
+/// - `toasts`: Notified once a submission succeeds or fails.
+pub fn contact_form(ui: &mut Ui, state: &mut FormState, endpoint: &str, toasts: &mut Toasts) {
+    let status = state.status();
+    let submitting = status == SubmitStatus::Submitting;
+    if status != state.announced {
+        match &status {
+            SubmitStatus::Success => toasts.push("Message sent", 3.0),
+            SubmitStatus::Error(_) => toasts.push("Couldn't send your message", 4.0),
+            SubmitStatus::Idle | SubmitStatus::Submitting => {}
+        }
+        state.announced = status.clone();
+    }
+
+    ui.add_enabled_ui(!submitting, |ui| {
+        ui.add(egui::TextEdit::singleline(&mut state.name).hint_text("Name"));
+        ui.add(egui::TextEdit::singleline(&mut state.email).hint_text("Email"));
+        ui.add(egui::TextEdit::multiline(&mut state.message).hint_text("Message").desired_rows(4));
+    });
+
+    ui.horizontal(|ui| {
+        let submit = ui.add(ButtonWithUnderline::new("Send").frame(false).enabled(!submitting));
+        if submit.clicked() {
+            state.validation_error = state.validate();
+            if state.validation_error.is_none() {
+                state.set_status(SubmitStatus::Submitting);
+                let mut request = ehttp::Request::post(endpoint, state.to_json().into_bytes());
+                request.headers = ehttp::Headers::new(&[("Accept", "*/*"), ("Content-Type", "application/json")]);
+                let shared_status = state.status.clone();
+                let ctx = ui.ctx().clone();
+                ehttp::fetch(request, move |result| {
+                    let status = match result {
+                        Ok(response) if response.ok => SubmitStatus::Success,
+                        Ok(response) => SubmitStatus::Error(format!("{} {}", response.status, response.status_text)),
+                        Err(error) => SubmitStatus::Error(error),
+                    };
+                    if let Ok(mut current) = shared_status.lock() {
+                        *current = status;
+                    }
+                    ctx.request_repaint();
+                });
+            }
+        }
+
+        // Inline feedback next to the button
+        if let Some(error) = state.validation_error {
+            ui.colored_label(ui.visuals().error_fg_color, error);
+        } else {
+            match &status {
+                SubmitStatus::Idle => {}
+                SubmitStatus::Submitting => {
+                    ui.spinner();
+                    ui.label("Sending…");
+                }
+                SubmitStatus::Success => {
+                    ui.label("Thanks! Your message was sent.");
+                }
+                SubmitStatus::Error(error) => {
+                    ui.colored_label(ui.visuals().error_fg_color, format!("Couldn't send your message: {error}"));
+                }
+            }
+        }
+    });
+}
+
+pub fn show_modal(ctx: &egui::Context, ui: &egui::Ui, len: usize, idx: usize, title: &String, img_path: &String, id: egui::Id) {
+    let image = Image::new(img_path).maintain_aspect_ratio(true).fit_to_original_size(2.).corner_radius(4);
+    let frame = Frame::group(&ctx.style()).stroke(Stroke::NONE).fill(Color32::from_black_alpha(200)).inner_margin(Margin::symmetric(16, 16));
+    let modal_response = egui::Modal::new(id).frame(frame).show(ctx, |ui| {
+        let maximum_size = ctx.screen_rect().size() - vec2(32.0, 32.0);
         ui.add(image.max_size(vec2(1000.0_f32.min(maximum_size.x), 800.0_f32.min(maximum_size.y))));
         // Paint a little gallery index preview
         ui.horizontal_top(|ui| {
@@ -597,13 +2739,1004 @@ pub fn show_modal(ctx: &egui::Context, ui: &egui::Ui, len: usize, idx: usize, ti
     }
 }
 
-fn open_link(link: &str, target: &str) {
-    if let Ok(_) = Url::new(link) { // Verifies valid link parsing
-        if let Some(window) = window() {
+/// The current location hash (including the leading `#`), or `None` without a browser location.
+pub fn current_hash() -> Option<String> {
+    window().and_then(|window| window.location().hash().ok())
+}
+
+/// The page's url without its hash, or `None` without a browser location.
+pub fn current_url_without_hash() -> Option<String> {
+    let href = window()?.location().href().ok()?;
+    Some(href.split_once('#').map_or(href.as_str(), |(url, _)| url).to_owned())
+}
+
+/// Sets the location hash, adding a history entry so the browser's Back button restores the previous one.
+pub fn set_location_hash(hash: &str) {
+    if let Some(Err(error)) = window().map(|window| window.location().set_hash(hash)) {
+        log::warn!("Failed to set the location hash to {hash}: {error:?}");
+    }
+}
+
+/// Sets the location hash without adding a history entry.
+fn replace_location_hash(hash: &str) {
+    if let Some(Err(error)) = window().map(|window| window.location().replace(hash)) {
+        log::warn!("Failed to replace the location hash with {hash}: {error:?}");
+    }
+}
+
+/// Where external links open, see [`set_link_target`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LinkTarget {
+    #[default]
+    NewTab,
+    SameTab, // For embeds (e.g. in an iframe) that shouldn't spawn new tabs
+}
+
+impl LinkTarget {
+    fn id() -> egui::Id {
+        egui::Id::new("link_target")
+    }
+}
+
+/// Sets where links opened by this module's widgets go from now on.
+/// - `ctx`: The egui context.
+/// - `target`: The new link target.
+pub fn set_link_target(ctx: &egui::Context, target: LinkTarget) {
+    ctx.data_mut(|data| data.insert_temp(LinkTarget::id(), target));
+}
+
+/// Where links opened by this module's widgets currently go, [`LinkTarget::NewTab`] unless set.
+pub fn link_target(ctx: &egui::Context) -> LinkTarget {
+    ctx.data(|data| data.get_temp(LinkTarget::id())).unwrap_or_default()
+}
+
+/// Sets the smallest size [`ButtonWithUnderline`] and [`socials`] are laid out at, e.g. 44×44 for comfortable tapping on phones.
+/// - `ctx`: The egui context.
+/// - `size`: The minimum size, [`Vec2::ZERO`] to size widgets to their contents.
+pub fn set_min_touch_target(ctx: &egui::Context, size: Vec2) {
+    ctx.data_mut(|data| data.insert_temp(egui::Id::new("min_touch_target"), size));
+}
+
+/// The minimum widget size set by [`set_min_touch_target`], [`Vec2::ZERO`] unless set.
+pub fn min_touch_target(ctx: &egui::Context) -> Vec2 {
+    ctx.data(|data| data.get_temp(egui::Id::new("min_touch_target"))).unwrap_or_default()
+}
+
+/// Sets the border drawn around project thumbnails from now on.
+/// - `ctx`: The egui context.
+/// - `stroke`: The border, e.g. 1px in the palette's `border` color, or `None` for no border.
+pub fn set_thumbnail_border(ctx: &egui::Context, stroke: Option<Stroke>) {
+    ctx.data_mut(|data| data.insert_temp(egui::Id::new("thumbnail_border"), stroke));
+}
+
+/// The border set by [`set_thumbnail_border`], none unless set.
+pub fn thumbnail_border(ctx: &egui::Context) -> Option<Stroke> {
+    ctx.data(|data| data.get_temp(egui::Id::new("thumbnail_border"))).flatten()
+}
+
+/// Sets the curve [`ButtonWithUnderline`] underlines follow between their resting and hover insets from now on.
+/// - `ctx`: The egui context.
+/// - `easing`: The curve, the move itself takes the style's `animation_time`.
+pub fn set_underline_easing(ctx: &egui::Context, easing: Easing) {
+    ctx.data_mut(|data| data.insert_temp(egui::Id::new("underline_easing"), easing));
+}
+
+/// The curve set by [`set_underline_easing`], [`Easing::EaseOut`] unless set.
+pub fn underline_easing(ctx: &egui::Context) -> Easing {
+    ctx.data(|data| data.get_temp(egui::Id::new("underline_easing"))).unwrap_or_default()
+}
+
+/// The browser window, or `None` outside a browser (e.g. native builds).
+/// `web_sys::window` panics on native targets, so DOM access goes through this instead.
+pub fn window() -> Option<web_sys::Window> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+/// The scheme of a link including its trailing colon (e.g. `"https:"`), or `None` if it doesn't parse as a url.
+fn link_scheme(link: &str) -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::Url::new(link).ok().map(|url| url.protocol())
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        // Without the browser's parser, accept a well-formed scheme followed by something to open
+        let (scheme, rest) = link.split_once(':')?;
+        let well_formed = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+        (well_formed && !rest.is_empty()).then(|| format!("{}:", scheme.to_ascii_lowercase()))
+    }
+}
+
+/// Why [`open_link`] would refuse the given link, or `None` if it opens.
+/// Debug builds only, to point out broken links on the page.
+#[cfg(debug_assertions)]
+fn link_problem(link: &str) -> Option<String> {
+    match link_scheme(link) {
+        None => Some("it doesn't parse as a url".to_owned()),
+        Some(scheme) if ["http:", "https:", "mailto:", "tel:"].contains(&scheme.as_str()) => None,
+        Some(scheme) => Some(format!("`{scheme}` links aren't opened, only http(s), mailto and tel")),
+    }
+}
+
+/// Opens a validated link through egui, which works in the browser and natively alike.
+/// - `http:`/`https:` links are opened with the given `target`.
+/// - `mailto:`/`tel:` links open in place so the OS handler picks them up, whatever the target.
+/// - Any other scheme (e.g. `javascript:`) is rejected.
+fn open_link(ctx: &egui::Context, link: &str, target: LinkTarget) {
+    match link_scheme(link) { // Verifies valid link parsing
+        Some(scheme) if scheme == "http:" || scheme == "https:" => {
             // Uses the link directly anyway since its been validated
-            let _ = window.open_with_url_and_target(link, target);
+            ctx.open_url(egui::OpenUrl { url: link.to_owned(), new_tab: target == LinkTarget::NewTab });
         }
-    } else {
-        log::debug!("Invalid URL: {}", link);
+        Some(scheme) if scheme == "mailto:" || scheme == "tel:" => {
+            ctx.open_url(egui::OpenUrl::same_tab(link));
+        }
+        _ => {
+            log::debug!("Invalid URL: {}", link);
+        }
+    }
+}
+
+/// Triggers a browser download of the file at the given url.
+/// Falls back to opening the file in a new tab if an anchor element can't be created, or outside a browser.
+/// - `ctx`: The egui context.
+/// - `url`: The absolute url of the file.
+/// - `file_name`: The suggested name for the saved file.
+pub fn download_file(ctx: &egui::Context, url: &str, file_name: &str) {
+    let anchor = window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.create_element("a").ok())
+        .and_then(|element| element.dyn_into::<HtmlAnchorElement>().ok());
+    match anchor {
+        Some(anchor) => {
+            anchor.set_href(url);
+            anchor.set_download(file_name);
+            anchor.click();
+        }
+        None => open_link(ctx, url, LinkTarget::NewTab),
+    }
+}
+
+/// Every widget laid out during `ctx`'s last pass, across all layers, for tests that scan the layout.
+#[cfg(test)]
+pub fn last_pass_widgets(ctx: &egui::Context) -> Vec<egui::WidgetRect> {
+    ctx.viewport(|viewport| viewport.prev_pass.widgets.layers().flat_map(|(_, widgets)| widgets.iter().copied()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::project;
+
+    /// Runs a single frame with the given contents inside a central panel.
+    fn run_ui(mut add_contents: impl FnMut(&mut Ui)) -> egui::FullOutput {
+        let ctx = egui::Context::default();
+        ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| add_contents(ui));
+        })
+    }
+
+    /// Runs two frames on the same context, moving the pointer to wherever `hover_at` points after the first.
+    /// Widgets only see hovers on the frame after they were laid out, so the second frame's output reflects the hover.
+    fn run_ui_hovered(mut add_contents: impl FnMut(&mut Ui) -> Rect, hover_at: impl Fn(Rect) -> Pos2) -> (Rect, egui::FullOutput) {
+        let ctx = egui::Context::default();
+        let mut rect = Rect::NOTHING;
+        let _: egui::FullOutput = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| rect = add_contents(ui));
+        });
+        let input = egui::RawInput {
+            events: vec![egui::Event::PointerMoved(hover_at(rect))],
+            ..Default::default()
+        };
+        let output = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| rect = add_contents(ui));
+        });
+        (rect, output)
+    }
+
+    /// Collects every line segment painted during the frame.
+    fn line_segments(output: &egui::FullOutput) -> Vec<[Pos2; 2]> {
+        output.shapes.iter().filter_map(|clipped| match &clipped.shape {
+            egui::Shape::LineSegment { points, .. } => Some(*points),
+            _ => None,
+        }).collect()
+    }
+
+    #[test]
+    fn underline_insets_mirror_in_rtl() {
+        let rect = Rect::from_min_size(pos2(10.0, 10.0), vec2(100.0, 20.0));
+        let margins = Margin::symmetric(2, 0);
+        let ltr = underline_points(rect, margins, vec2(8.0, 0.0), false);
+        let rtl = underline_points(rect, margins, vec2(8.0, 0.0), true);
+        assert_eq!(ltr, [pos2(20.0, 30.0), pos2(108.0, 30.0)]);
+        assert_eq!(rtl, [pos2(12.0, 30.0), pos2(100.0, 30.0)]);
+    }
+
+    #[test]
+    fn underline_spans_button_in_both_directions() {
+        for rtl in [false, true] {
+            let mut button_rect = Rect::NOTHING;
+            let output = run_ui(|ui| {
+                button_rect = ui.add(ButtonWithUnderline::new("Portfolio").right_text("→").frame(false).inset([4.0, 0.0]).rtl(rtl)).rect;
+            });
+            let expected = underline_points(button_rect, Margin::ZERO, vec2(4.0, 0.0), rtl);
+            let segments = line_segments(&output);
+            assert!(segments.contains(&expected), "rtl={rtl}: expected underline {expected:?} in {segments:?}");
+            assert!(expected[0].x < expected[1].x, "rtl={rtl}: underline should have a positive length");
+            assert!(button_rect.x_range().contains(expected[0].x) && button_rect.x_range().contains(expected[1].x), "rtl={rtl}: underline should stay within the button");
+        }
+    }
+
+    #[test]
+    fn content_underline_hugs_the_text() {
+        let underline = |fit: UnderlineFit| {
+            let (mut button, mut text_width) = (Rect::NOTHING, 0.0);
+            let output = run_ui(|ui| {
+                // An unframed, unstretched button is exactly as wide as its text
+                text_width = ui.add(ButtonWithUnderline::new("Portfolio").frame(false)).rect.width();
+                button = ui.add(ButtonWithUnderline::new("Portfolio").min_size(vec2(300.0, 0.0)).underline_fit(fit).inset([2.0, 2.0])).rect;
+            });
+            (button, text_width, line_segments(&output)[1])
+        };
+        let padding = egui::Style::default().spacing.button_padding.x;
+
+        let (button, _, full) = underline(UnderlineFit::Full);
+        assert_eq!(full[1].x, button.right() - padding - 2.0, "full underlines span the stretched button");
+
+        let (button, text_width, content) = underline(UnderlineFit::Content);
+        assert_eq!(content[0].x, button.left() + padding + 2.0);
+        assert_eq!(content[1].x, button.left() + padding + text_width - 2.0, "content underlines stop at the end of the text");
+    }
+
+    #[test]
+    fn button_senses_clicks_unless_disabled() {
+        let mut responses = Vec::new();
+        run_ui(|ui| {
+            responses.push(ui.add(ButtonWithUnderline::new("Enabled")));
+            responses.push(ui.add(ButtonWithUnderline::new("Disabled").enabled(false)));
+        });
+        let [enabled, disabled] = [&responses[0], &responses[1]];
+        assert!(enabled.rect.width() > 0.0 && enabled.rect.height() > 0.0, "button should take up space");
+        assert!(enabled.sense.senses_click());
+        assert_eq!(disabled.sense, Sense::hover(), "disabled buttons should only sense hovers");
+    }
+
+    #[test]
+    fn button_hover_switches_to_hover_inset() {
+        let button = || ButtonWithUnderline::new("Portfolio").frame(false).inset([6.0, 6.0]).hover_inset([0.0, 0.0]);
+
+        let mut rect = Rect::NOTHING;
+        let resting = run_ui(|ui| rect = ui.add(button()).rect);
+        let resting_underline = underline_points(rect, Margin::ZERO, vec2(6.0, 6.0), false);
+        assert!(line_segments(&resting).contains(&resting_underline), "resting underline should use the inset");
+
+        // Without animations (e.g. reduced motion) the underline jumps straight to the hover inset
+        let (rect, hovered) = run_ui_hovered(|ui| {
+            ui.style_mut().animation_time = 0.0;
+            ui.add(button()).rect
+        }, |rect| rect.center());
+        let hovered_underline = underline_points(rect, Margin::ZERO, Vec2::ZERO, false);
+        assert!(line_segments(&hovered).contains(&hovered_underline), "hovered underline should use the hover inset");
+    }
+
+    #[test]
+    fn easing_curves_keep_their_endpoints() {
+        for easing in [Easing::Linear, Easing::EaseOut, Easing::EaseInOut] {
+            assert_eq!(easing.apply(0.0), 0.0, "{easing:?}");
+            assert_eq!(easing.apply(1.0), 1.0, "{easing:?}");
+            assert_eq!(easing.apply(2.0), 1.0, "{easing:?} should clamp");
+        }
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+        assert!(Easing::EaseOut.apply(0.25) > 0.25, "ease-out should move quickly at first");
+    }
+
+    #[test]
+    fn selected_button_keeps_resting_underline() {
+        // Selection changes the visuals, but only hovering moves the underline
+        let mut rect = Rect::NOTHING;
+        let output = run_ui(|ui| rect = ui.add(ButtonWithUnderline::selectable(true, "Rust").inset([6.0, 6.0]).hover_inset([0.0, 0.0])).rect);
+        // Framed buttons pad their contents, and the underline sits inside that padding
+        let margins = Margin::from(egui::Style::default().spacing.button_padding);
+        let expected = underline_points(rect, margins, vec2(6.0, 6.0), false);
+        assert!(line_segments(&output).contains(&expected), "expected {expected:?} in {:?}", line_segments(&output));
+    }
+
+    #[test]
+    fn icon_button_announces_accessible_label() {
+        let ctx = egui::Context::default();
+        let frame = |events: Vec<egui::Event>| {
+            let mut rect = Rect::NOTHING;
+            let output = ctx.run(egui::RawInput { events, ..Default::default() }, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    rect = ui.add(ButtonWithUnderline::icon_button(egui::include_image!("../assets/croissant.png")).accessible_label("Home")).rect;
+                });
+            });
+            (rect, output)
+        };
+        let (rect, _) = frame(Vec::new());
+        let pointer = |pressed| egui::Event::PointerButton { pos: rect.center(), button: egui::PointerButton::Primary, pressed, modifiers: egui::Modifiers::NONE };
+        let _: (Rect, egui::FullOutput) = frame(vec![egui::Event::PointerMoved(rect.center()), pointer(true)]);
+        let (_, output) = frame(vec![pointer(false)]);
+
+        let clicked = output.platform_output.events.iter().find_map(|event| match event {
+            egui::output::OutputEvent::Clicked(info) => Some(info),
+            _ => None,
+        });
+        assert_eq!(clicked.and_then(|info| info.label.as_deref()), Some("Home"));
+    }
+
+    #[test]
+    fn badge_widens_button_unless_empty() {
+        let mut widths = Vec::new();
+        run_ui(|ui| {
+            widths.push(ui.add(ButtonWithUnderline::new("Projects")).rect.width());
+            widths.push(ui.add(ButtonWithUnderline::new("Projects").badge("")).rect.width());
+            widths.push(ui.add(ButtonWithUnderline::new("Projects").badge("12")).rect.width());
+        });
+        assert_eq!(widths[0], widths[1], "an empty badge should not take up space");
+        assert!(widths[2] > widths[0], "the badge should be part of the button's layout: {widths:?}");
+    }
+
+    #[test]
+    fn skill_frameplate_sizes() {
+        let mut rects = Vec::new();
+        run_ui(|ui| {
+            let icon = Some("file://icon.png".to_owned());
+            rects.push(ui.scope(|ui| skill_frameplate(ui, "Rust", Color32::RED, Color32::WHITE, &None, 14.0)).response.rect);
+            rects.push(ui.scope(|ui| skill_frameplate(ui, "Rust", Color32::RED, Color32::WHITE, &icon, 14.0)).response.rect);
+            let fixed = SkillChipStyle { height: Some(30.0), ..Default::default() };
+            rects.push(ui.scope(|ui| skill_frameplate_sized(ui, "Rust", Color32::RED, Color32::WHITE, &None, 14.0, &fixed)).response.rect);
+            rects.push(ui.scope(|ui| skill_frameplate_sized(ui, "Rust", Color32::RED, Color32::WHITE, &icon, 14.0, &fixed)).response.rect);
+        });
+        let spacing = SkillChipStyle::default().spacing;
+        assert!((rects[1].width() - rects[0].width() - (16.0 + spacing)).abs() < 0.5, "the icon should widen the chip by its size and spacing: {rects:?}");
+        assert_eq!(rects[2].height(), 30.0);
+        assert_eq!(rects[3].height(), 30.0, "fixed-height chips line up with or without an icon");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn socials_link_color_is_readable_in_both_themes() {
+        use crate::{app::TemplateApp, color::contrast_ratio, data::Palette};
+
+        let ctx = egui::Context::default();
+        let styles = [
+            ("dark", TemplateApp::get_dark_theme_style(&ctx, &Palette::dark())),
+            ("light", TemplateApp::get_light_theme_style(&ctx, &Palette::light())),
+        ];
+        for (name, style) in styles {
+            let output = run_ui(|ui| {
+                ui.set_style(style.clone());
+                socials(ui, "github/@ZeroUni", "https://github.com/ZeroUni", &None, 14.0);
+            });
+            let link_color = output.shapes.iter().find_map(|clipped| match &clipped.shape {
+                egui::Shape::Text(text) => text.galley.job.sections.first().map(|section| section.format.color),
+                _ => None,
+            }).expect("socials should paint its label");
+
+            assert_eq!(link_color, style.visuals.hyperlink_color, "{name}: socials should use the theme's link color");
+            let ratio = contrast_ratio(link_color, style.visuals.panel_fill);
+            assert!(ratio >= 4.5, "{name}: link contrast against the panel is only {ratio:.2}:1");
+        }
+    }
+
+    #[test]
+    fn socials_show_pointing_hand_on_hover() {
+        let (_, output) = run_ui_hovered(
+            |ui| ui.scope(|ui| socials(ui, "github/@ZeroUni", "https://github.com/ZeroUni", &None, 14.0)).response.rect,
+            |rect| rect.center(),
+        );
+        assert_eq!(output.platform_output.cursor_icon, egui::CursorIcon::PointingHand);
+    }
+
+    #[test]
+    fn focus_is_only_visible_after_keyboard_navigation() {
+        let ctx = egui::Context::default();
+        let run = |events: Vec<egui::Event>| {
+            let mut state = (Rect::NOTHING, false, false);
+            let _: egui::FullOutput = ctx.run(egui::RawInput { events, ..Default::default() }, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let response = ui.add(ButtonWithUnderline::new("Portfolio"));
+                    state = (response.rect, response.has_focus(), focus_visible(ui, &response));
+                });
+            });
+            state
+        };
+        let (rect, ..) = run(Vec::new());
+
+        let tab = egui::Event::Key { key: egui::Key::Tab, physical_key: None, pressed: true, repeat: false, modifiers: egui::Modifiers::NONE };
+        assert_eq!(run(vec![tab]), (rect, true, true), "Tab should show the focus ring");
+
+        let click = |pressed| egui::Event::PointerButton { pos: rect.center(), button: egui::PointerButton::Primary, pressed, modifiers: egui::Modifiers::NONE };
+        run(vec![egui::Event::PointerMoved(rect.center()), click(true)]);
+        let (_, focused, visible) = run(vec![click(false)]);
+        assert!(focused, "clicking should keep the button focused");
+        assert!(!visible, "clicking shouldn't show the focus ring");
+    }
+
+    #[test]
+    fn tab_visits_widgets_in_document_order() {
+        let ctx = egui::Context::default();
+        let add_contents = |ui: &mut Ui| vec![
+            ui.add(ButtonWithUnderline::new("Portfolio")).id,
+            socials(ui, "github/@ZeroUni", "https://github.com/ZeroUni", &None, 14.0).id,
+            ui.add(egui::Label::new("Project").sense(Sense::click())).id,
+            socials(ui, "linkedin/@ZeroUni", "https://www.linkedin.com/in/ZeroUni", &None, 14.0).id,
+        ];
+        let mut ids = Vec::new();
+        let _: egui::FullOutput = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| ids = add_contents(ui));
+        });
+
+        let mut visited = Vec::new();
+        for _ in 0..ids.len() {
+            let input = egui::RawInput {
+                events: vec![egui::Event::Key { key: egui::Key::Tab, physical_key: None, pressed: true, repeat: false, modifiers: egui::Modifiers::NONE }],
+                ..Default::default()
+            };
+            let _: egui::FullOutput = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    add_contents(ui);
+                });
+            });
+            visited.push(ctx.memory(|memory| memory.focused()).expect("Tab should focus a widget"));
+        }
+        assert_eq!(visited, ids);
+    }
+
+    #[test]
+    fn scroll_fades_follow_clipped_edges() {
+        assert_eq!(clipped_edges(0.0, 100.0, 100.0), [false, false], "content that fits needs no fades");
+        assert_eq!(clipped_edges(0.0, 100.0, 300.0), [false, true]);
+        assert_eq!(clipped_edges(100.0, 100.0, 300.0), [true, true]);
+        assert_eq!(clipped_edges(200.0, 100.0, 300.0), [true, false]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn invalid_social_links_are_flagged_in_debug_builds() {
+        assert_eq!(link_problem("https://github.com/ZeroUni"), None);
+        assert_eq!(link_problem("mailto:me@example.com"), None);
+        assert!(link_problem("github.com/ZeroUni").is_some());
+        assert!(link_problem("javascript:alert(1)").is_some_and(|problem| problem.contains("`javascript:`")));
+
+        let output = run_ui(|ui| {
+            socials(ui, "github", "htps//github.com/ZeroUni", &None, 14.0);
+        });
+        let error_color = egui::Visuals::dark().error_fg_color;
+        let flagged = output.shapes.iter().any(|clipped| match &clipped.shape {
+            egui::Shape::Text(text) => text.galley.text() == "⚠" && text.galley.job.sections.iter().all(|section| section.format.color == error_color),
+            _ => false,
+        });
+        assert!(flagged, "a link that won't open should get a warning glyph");
+    }
+
+    #[test]
+    fn native_links_keep_their_scheme_validation() {
+        assert_eq!(link_scheme("https://github.com/ZeroUni").as_deref(), Some("https:"));
+        assert_eq!(link_scheme("MAILTO:me@example.com").as_deref(), Some("mailto:"));
+        assert_eq!(link_scheme("javascript:alert(1)").as_deref(), Some("javascript:"), "rejected by `open_link`, not the parser");
+        assert_eq!(link_scheme("not a link"), None);
+        assert_eq!(link_scheme("1http://example.com"), None);
+        assert_eq!(link_scheme("https:"), None);
+    }
+
+    #[test]
+    fn cover_crops_the_longer_side_evenly() {
+        let square = vec2(128.0, 128.0);
+        assert_eq!(cover_uv(vec2(400.0, 200.0), square), Rect::from_min_max(pos2(0.25, 0.0), pos2(0.75, 1.0)));
+        assert_eq!(cover_uv(vec2(200.0, 800.0), square), Rect::from_min_max(pos2(0.0, 0.375), pos2(1.0, 0.625)));
+        assert_eq!(cover_uv(square, square), Rect::from_min_max(Pos2::ZERO, pos2(1.0, 1.0)));
+    }
+
+    #[test]
+    fn gradient_midpoint_is_linear_light() {
+        let rect = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0));
+        let mesh = angular_gradient_mesh(rect, Color32::BLACK, Color32::WHITE, 0.0, Vec2::splat(1.0));
+        let midpoint = mesh.vertices.iter().find(|vertex| vertex.pos == rect.center()).expect("mesh should have a vertex at the center");
+        // Half-way in linear light is much brighter than the gamma-space midpoint of 128
+        assert_eq!(midpoint.color, Color32::from(Rgba::from_gray(0.5)));
+        assert_ne!(midpoint.color, Color32::from_gray(128));
+        // The edges still hit the exact start (bottom) and end (top) colors
+        assert_eq!(mesh.vertices.last().map(|vertex| vertex.color), Some(Color32::BLACK));
+        assert_eq!(mesh.vertices.first().map(|vertex| vertex.color), Some(Color32::WHITE));
+    }
+
+    /// The color a black to white gradient paints at blend factor `f`.
+    fn gray_at(f: f32) -> Color32 {
+        Color32::from(Rgba::from_gray(f))
+    }
+
+    /// Where each corner of `rect` sits along the gradient, ordered left-top, right-top, right-bottom, left-bottom.
+    fn corner_factors(rect: Rect, angle_rad: f32) -> [f32; 4] {
+        [rect.left_top(), rect.right_top(), rect.right_bottom(), rect.left_bottom()]
+            .map(|corner| gradient_factor_at(rect, angle_rad, corner))
+    }
+
+    /// The color [`paint_angular_gradient`] gives each corner of `rect`, in the order of [`corner_factors`].
+    fn gradient_corner_colors(rect: Rect, start_color: Color32, end_color: Color32, angle_rad: f32, intensity: Vec2) -> [Color32; 4] {
+        let (start_rgba, end_rgba) = (Rgba::from(start_color), Rgba::from(end_color));
+        corner_factors(rect, angle_rad).map(|t| gradient_color(start_rgba, end_rgba, t, intensity))
+    }
+
+    #[test]
+    fn gradient_corners_vertical() {
+        let rect = Rect::from_min_size(Pos2::ZERO, vec2(200.0, 100.0));
+        // Angle 0 puts the start color at the bottom and the end color at the top
+        let corners = gradient_corner_colors(rect, Color32::BLACK, Color32::WHITE, 0.0, Vec2::splat(1.0));
+        assert_eq!(corners, [Color32::WHITE, Color32::WHITE, Color32::BLACK, Color32::BLACK]);
+    }
+
+    #[test]
+    fn gradient_corners_horizontal() {
+        let rect = Rect::from_min_size(Pos2::ZERO, vec2(200.0, 100.0));
+        // A quarter turn runs the gradient from left to right
+        let corners = gradient_corner_colors(rect, Color32::BLACK, Color32::WHITE, std::f32::consts::FRAC_PI_2, Vec2::splat(1.0));
+        assert_eq!(corners, [Color32::BLACK, Color32::WHITE, Color32::WHITE, Color32::BLACK]);
+    }
+
+    #[test]
+    fn gradient_corners_diagonal_intensity() {
+        // On a 2:1 rect at 45°, the left-top and right-bottom corners sit a third and two thirds along the gradient
+        let rect = Rect::from_min_size(Pos2::ZERO, vec2(200.0, 100.0));
+        let angle = std::f32::consts::FRAC_PI_4;
+        let factors = corner_factors(rect, angle);
+        for (factor, expected) in factors.iter().zip([1.0 / 3.0, 1.0, 2.0 / 3.0, 0.0]) {
+            assert!((factor - expected).abs() < 1e-4, "expected {expected}, got {factor}");
+        }
+
+        // Symmetric intensity blends across the whole gradient
+        let symmetric = gradient_corner_colors(rect, Color32::BLACK, Color32::WHITE, angle, Vec2::splat(1.0));
+        assert_eq!(symmetric, [gray_at(factors[0]), Color32::WHITE, gray_at(factors[2]), Color32::BLACK]);
+
+        // A start intensity of 2.0 holds the start color until a quarter of the way along
+        let asymmetric = gradient_corner_colors(rect, Color32::BLACK, Color32::WHITE, angle, vec2(2.0, 1.0));
+        let remap = |t: f32| (t - 0.25) / 0.75;
+        assert_eq!(asymmetric, [gray_at(remap(factors[0])), Color32::WHITE, gray_at(remap(factors[2])), Color32::BLACK]);
+    }
+
+    #[test]
+    fn gradient_axis_aligned_angles_are_exact() {
+        use std::f32::consts::{FRAC_PI_2, PI, TAU};
+        let rect = Rect::from_min_size(Pos2::ZERO, vec2(200.0, 100.0));
+        // Corners are left-top, right-top, right-bottom, left-bottom
+        let cases = [
+            (0.0, [1.0, 1.0, 0.0, 0.0]),
+            (FRAC_PI_2, [0.0, 1.0, 1.0, 0.0]),
+            (PI, [0.0, 0.0, 1.0, 1.0]),
+            (3.0 * FRAC_PI_2, [1.0, 0.0, 0.0, 1.0]),
+        ];
+        for (angle, expected) in cases {
+            // Whole turns either way land on the same gradient
+            for turns in [-1.0, 0.0, 1.0, 2.0] {
+                assert_eq!(corner_factors(rect, angle + turns * TAU), expected, "angle {angle} + {turns} turns");
+            }
+            assert_eq!(corner_factors(rect, angle.to_degrees().to_radians()), expected, "angle {angle} via degrees");
+        }
+    }
+
+    #[test]
+    fn gradient_axis_aligned_degenerate_rects_fall_back_to_midpoint() {
+        use std::f32::consts::{FRAC_PI_2, PI};
+        // Without snapping, cos(π/2) ≈ -4e-8 would spread a zero-width rect across the whole gradient
+        let zero_width = Rect::from_min_size(Pos2::ZERO, vec2(0.0, 100.0));
+        assert_eq!(corner_factors(zero_width, FRAC_PI_2), [0.5; 4]);
+        assert_eq!(corner_factors(zero_width, -FRAC_PI_2), [0.5; 4]);
+        let zero_height = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 0.0));
+        assert_eq!(corner_factors(zero_height, PI), [0.5; 4]);
+        // Along the rect's extent the gradient still spans it
+        assert_eq!(corner_factors(zero_width, 0.0), [1.0, 1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn gradient_degenerate_rect_falls_back_to_midpoint() {
+        // A zero-height rect has no extent along a vertical gradient
+        let rect = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 0.0));
+        assert_eq!(corner_factors(rect, 0.0), [0.5; 4]);
+        let corners = gradient_corner_colors(rect, Color32::BLACK, Color32::WHITE, 0.0, Vec2::splat(1.0));
+        assert_eq!(corners, [gray_at(0.5); 4]);
+    }
+
+    #[test]
+    fn gradient_fades_alpha_without_darkening() {
+        let rect = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0));
+        let accent = Color32::from_rgba_unmultiplied(200, 40, 90, 255);
+        let transparent = Color32::from_rgba_unmultiplied(200, 40, 90, 0);
+        let mesh = angular_gradient_mesh(rect, accent, transparent, 0.0, Vec2::splat(1.0));
+
+        // Angle 0 runs bottom to top, so alpha should grow row by row going down
+        let steps = GRADIENT_SUBDIVISIONS as usize;
+        let row_alphas: Vec<u8> = (0..=steps).map(|row| mesh.vertices[row * (steps + 1)].color.a()).collect();
+        assert_eq!(row_alphas.first(), Some(&0));
+        assert_eq!(row_alphas.last(), Some(&255));
+        assert!(row_alphas.windows(2).all(|pair| pair[0] < pair[1]), "alpha should strictly increase: {row_alphas:?}");
+
+        // Partially transparent vertices keep the accent's hue once unmultiplied
+        let [r, g, b, a] = mesh.vertices[(steps / 2) * (steps + 1)].color.to_srgba_unmultiplied();
+        assert!(a > 0 && a < 255, "midpoint should be partially transparent, got {a}");
+        for (channel, expected) in [(r, 200), (g, 40), (b, 90)] {
+            assert!(channel.abs_diff(expected) <= 3, "expected {expected}, got {channel}");
+        }
+    }
+
+    #[test]
+    fn conic_color_stops_wrap_around_the_seam() {
+        let stops = [(0.25, Color32::BLACK), (0.75, Color32::WHITE)];
+        assert_eq!(sample_color_stops(&stops, 0.25), Color32::BLACK);
+        assert_eq!(sample_color_stops(&stops, 0.5), gray_at(0.5));
+        assert_eq!(sample_color_stops(&stops, 0.75), Color32::WHITE);
+        // Past the last stop the sweep blends back into the first one, meeting at the seam without a jump
+        assert_eq!(sample_color_stops(&stops, 0.875), gray_at(0.75));
+        assert_eq!(sample_color_stops(&stops, 1.0), gray_at(0.5));
+        assert_eq!(sample_color_stops(&stops, 0.0), gray_at(0.5));
+        assert_eq!(sample_color_stops(&stops, 0.125), gray_at(0.25));
+        // A single stop is a solid fill
+        assert_eq!(sample_color_stops(&stops[..1], 0.6), Color32::BLACK);
+        assert_eq!(sample_color_stops(&[], 0.5), Color32::TRANSPARENT);
+    }
+
+    #[test]
+    fn email_check_table() {
+        let cases = [
+            ("", false),
+            ("ada", false),
+            ("ada.example.com", false),
+            ("@example.com", false),
+            ("ada@", false),
+            ("ada@example", false),
+            ("ada@.com", false),
+            ("ada@example.", false),
+            ("ada@home@example.com", false),
+            ("ada lovelace@example.com", false),
+            ("ada\n@example.com", false),
+            ("ada@example.com", true),
+            ("ada.lovelace+site@mail.example.co.uk", true),
+        ];
+        for (email, expected) in cases {
+            assert_eq!(looks_like_email(email), expected, "{email:?}");
+        }
+    }
+
+    #[test]
+    fn json_escape_table() {
+        let cases = [
+            ("", ""),
+            ("plain text", "plain text"),
+            ("say \"hi\"", "say \\\"hi\\\""),
+            ("C:\\temp", "C:\\\\temp"),
+            ("line one\nline two", "line one\\nline two"),
+            ("tab\there\r\n", "tab\\there\\r\\n"),
+            ("bell\u{7}", "bell\\u0007"),
+            ("héllo ✓", "héllo ✓"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(json_escape(value), expected, "{value:?}");
+        }
+    }
+
+    #[test]
+    fn arrow_keys_move_focus_between_cards() {
+        let ctx = egui::Context::default();
+        // Five cards in rows of two, the last row holding only one
+        let frame = |key: Option<egui::Key>| {
+            let events = key.map(|key| egui::Event::Key { key, physical_key: None, pressed: true, repeat: false, modifiers: egui::Modifiers::NONE });
+            let mut ids = Vec::new();
+            let _: egui::FullOutput = ctx.run(egui::RawInput { events: events.into_iter().collect(), ..Default::default() }, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let grid: Vec<Vec<ProjectCard>> = (0..3).map(|row| {
+                        ui.horizontal(|ui| {
+                            (row * 2..(row * 2 + 2).min(5)).map(|index| {
+                                let response = ui.button(format!("Project {index}"));
+                                ProjectCard { open_details: false, title_id: response.id, focused: response.has_focus().then_some(response.id) }
+                            }).collect()
+                        }).inner
+                    }).collect();
+                    ids = grid.iter().flatten().map(|card| card.title_id).collect();
+                    navigate_cards(ctx, &grid);
+                });
+            });
+            ids
+        };
+        let ids = frame(None);
+        ctx.memory_mut(|memory| memory.request_focus(ids[0]));
+        let press = |key: egui::Key| {
+            // Let the focus settle for a frame first, as a visitor pressing keys one after the other would
+            frame(None);
+            frame(Some(key));
+            ctx.memory(|memory| memory.focused()).and_then(|focused| ids.iter().position(|id| *id == focused))
+        };
+        assert_eq!(press(egui::Key::ArrowRight), Some(1));
+        assert_eq!(press(egui::Key::ArrowDown), Some(3));
+        assert_eq!(press(egui::Key::ArrowDown), Some(4), "the shorter last row takes its last card");
+        assert_eq!(press(egui::Key::ArrowDown), Some(4), "nothing below the last row");
+        assert_eq!(press(egui::Key::ArrowLeft), Some(3));
+        assert_eq!(press(egui::Key::ArrowUp), Some(1));
+        assert_eq!(press(egui::Key::ArrowLeft), Some(0));
+        assert_eq!(press(egui::Key::ArrowLeft), Some(0), "nothing before the first card");
+    }
+
+    #[test]
+    fn timeline_alternates_only_on_large_screens() {
+        let entries: Vec<TimelineEntry> = ["First", "Second", "Third"].into_iter().map(|title| TimelineEntry {
+            title: title.to_owned(),
+            org: "Org".to_owned(),
+            period: "2024".to_owned(),
+            description: String::new(),
+        }).collect();
+        // The line's x and the painted rect of each title, top to bottom. Glyph bearings shift titles by a pixel or so
+        let layout = |screen_size| {
+            let output = run_ui(|ui| timeline(ui, &entries, screen_size));
+            let titles: Vec<Rect> = entries.iter().filter_map(|entry| output.shapes.iter().find_map(|clipped| match &clipped.shape {
+                egui::Shape::Text(text) if text.galley.text() == entry.title => Some(text.visual_bounding_rect()),
+                _ => None,
+            })).collect();
+            let [[top, bottom]] = line_segments(&output)[..] else { panic!("expected one line") };
+            assert_eq!(top.x, bottom.x, "the line is vertical");
+            assert_eq!(titles.len(), entries.len());
+            (top.x, titles)
+        };
+        let (line_x, titles) = layout(ScreenSize::Large);
+        assert!(titles[0].right() < line_x && titles[2].right() < line_x, "even entries sit left of the line");
+        assert!(titles[1].left() > line_x, "odd entries sit right of the line");
+        for screen_size in [ScreenSize::Medium, ScreenSize::Small] {
+            let (line_x, titles) = layout(screen_size);
+            assert!(titles.iter().all(|title| title.left() > line_x && (title.left() - titles[0].left()).abs() < 2.0), "{screen_size:?} stacks every entry right of the line");
+            assert!(titles.windows(2).all(|pair| pair[0].bottom() < pair[1].top()), "{screen_size:?} keeps the entries in order");
+        }
+    }
+
+    #[test]
+    fn skill_cloud_sizes_follow_levels() {
+        let skills = |levels: &[Option<u8>]| -> Vec<Skill> {
+            levels.iter().map(|&level| Skill { name: String::new(), rgb: [0; 3], text_rgb: [0; 3], icon: None, level }).collect()
+        };
+        let sizes = |skills: &[Skill]| skill_cloud_font_sizes(&skills.iter().collect::<Vec<_>>());
+        // Levels 1 to 5 map onto 12pt to 28pt, 4pt apart
+        assert_eq!(sizes(&[]), Vec::<f32>::new());
+        assert_eq!(sizes(&skills(&[None, None])), [20.0, 20.0], "without any levels every skill takes the middle size");
+        assert_eq!(sizes(&skills(&[Some(4), Some(4), Some(4)])), [24.0, 24.0, 24.0]);
+        assert_eq!(sizes(&skills(&[Some(1), None, Some(5), Some(2)])), [12.0, 16.0, 28.0, 16.0], "unleveled skills take the median level");
+        assert_eq!(sizes(&skills(&[Some(1), None, Some(5), Some(2), Some(4)])), [12.0, 20.0, 28.0, 16.0, 24.0], "an even count averages the middle two");
+    }
+
+    #[test]
+    fn repeated_labels_do_not_clash() {
+        let mut projects = [project("maps-v1", &[]), project("maps-v2", &[])];
+        let mut social_ids = Vec::new();
+        let mut title_ids = Vec::new();
+        let output = run_ui(|ui| {
+            let ctx = ui.ctx().clone();
+            for link in ["https://example.com/a", "https://example.com/b"] {
+                social_ids.push(socials(ui, "Blog", link, &None, 16.0).id);
+            }
+            for _ in 0..2 {
+                skill_frameplate(ui, "rust", Color32::RED, Color32::WHITE, &None, 16.0);
+            }
+            for project in &mut projects {
+                title_ids.push(add_highlighted_project(ui, &ctx, &String::new(), project, false, ScreenSize::Large).title_id);
+            }
+        });
+        // egui paints a warning starting with 🔥 over widgets whose ids clash
+        let clashes = output.shapes.iter().filter(|clipped| matches!(&clipped.shape,
+            egui::Shape::Text(text) if text.galley.text().starts_with('🔥'))).count();
+        assert_eq!(clashes, 0);
+        assert_ne!(social_ids[0], social_ids[1]);
+        assert_ne!(title_ids[0], title_ids[1]);
+    }
+
+    /// Records the size every image is requested at, leaving it pending.
+    #[derive(Default)]
+    struct SizeHintRecorder(Mutex<Vec<(String, egui::SizeHint)>>);
+
+    impl egui::load::ImageLoader for SizeHintRecorder {
+        fn id(&self) -> &str {
+            "size_hint_recorder"
+        }
+
+        fn load(&self, _ctx: &egui::Context, uri: &str, size_hint: egui::SizeHint) -> egui::load::ImageLoadResult {
+            self.0.lock().unwrap_or_else(std::sync::PoisonError::into_inner).push((uri.to_owned(), size_hint));
+            Ok(egui::load::ImagePoll::Pending { size: Some(Vec2::splat(24.0)) })
+        }
+
+        fn forget(&self, _uri: &str) {}
+
+        fn forget_all(&self) {}
+
+        fn byte_size(&self) -> usize {
+            0
+        }
+    }
+
+    #[test]
+    fn svg_icons_load_at_device_pixel_size() {
+        let ctx = egui::Context::default();
+        let recorder = Arc::new(SizeHintRecorder::default());
+        ctx.add_image_loader(recorder.clone());
+        let mut input = egui::RawInput::default();
+        input.viewports.entry(egui::ViewportId::ROOT).or_default().native_pixels_per_point = Some(2.0);
+        let _: egui::FullOutput = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                socials(ui, "Blog", "https://example.com", &Some("/assets/blog.svg".to_owned()), 14.0);
+            });
+        });
+        // A 16×16 icon on a 2× display is rasterized at 32×32 pixels rather than scaled up
+        let hints = recorder.0.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        assert!(!hints.is_empty(), "the icon should have been loaded");
+        for (uri, hint) in hints.iter() {
+            assert_eq!(uri, "/assets/blog.svg");
+            assert!(matches!(hint, egui::SizeHint::Size { width: 32, height: 32, .. }), "unexpected size hint {hint:?}");
+        }
+    }
+
+    #[test]
+    fn toasts_fade_out_after_expiring() {
+        let ctx = egui::Context::default();
+        let clock = crate::clock::FakeClock::install(&ctx);
+        let fade = f64::from(ctx.style().animation_time);
+        let mut toasts = Toasts::default();
+        toasts.push("Copied to clipboard", 1.0);
+        let mut shown_after = |seconds: f64| {
+            clock.advance(seconds);
+            let _: egui::FullOutput = ctx.run(egui::RawInput::default(), |ctx| toasts.show(ctx));
+            toasts.toasts.len()
+        };
+        assert_eq!(shown_after(0.0), 1);
+        assert_eq!(shown_after(0.99), 1, "still up until it expires");
+        assert_eq!(shown_after(0.01), 1, "starts fading out");
+        assert_eq!(shown_after(fade / 2.0), 1, "halfway faded out");
+        assert_eq!(shown_after(fade), 0, "dropped once faded out");
+    }
+
+    #[test]
+    fn underline_inset_eases_toward_the_hover_inset() {
+        let ctx = egui::Context::default();
+        let clock = crate::clock::FakeClock::install(&ctx);
+        let half = f64::from(ctx.style().animation_time) / 2.0;
+        let rect = std::cell::Cell::new(Rect::NOTHING);
+        // The leading inset of the underline once the clock moved forward and the events arrived
+        let inset_after = |seconds: f64, events: Vec<egui::Event>| {
+            clock.advance(seconds);
+            let output = ctx.run(egui::RawInput { events, ..Default::default() }, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    rect.set(ui.add(ButtonWithUnderline::new("Portfolio").frame(false).inset([6.0, 6.0]).hover_inset([0.0, 0.0])).rect);
+                });
+            });
+            let underline = line_segments(&output).into_iter().find(|[start, _]| start.y == rect.get().bottom());
+            underline.map(|[start, _]| start.x - rect.get().left()).expect("the button is underlined")
+        };
+        let halfway = 6.0 * (1.0 - Easing::EaseOut.apply(0.5));
+        assert_eq!(inset_after(0.0, Vec::new()), 6.0);
+        assert_eq!(inset_after(0.0, vec![egui::Event::PointerMoved(rect.get().center())]), 6.0, "hovering starts the move");
+        assert!((inset_after(half, Vec::new()) - halfway).abs() < 1e-4, "eased halfway in");
+        assert_eq!(inset_after(half, Vec::new()), 0.0);
+        assert_eq!(inset_after(0.0, vec![egui::Event::PointerMoved(pos2(1000.0, 1000.0))]), 0.0, "moving away starts the way back");
+        assert!((inset_after(half, Vec::new()) - halfway).abs() < 1e-4, "eased halfway out");
+        assert_eq!(inset_after(half, Vec::new()), 6.0);
+    }
+
+    #[test]
+    fn revealed_contents_fade_in_with_the_clock() {
+        let ctx = egui::Context::default();
+        let clock = crate::clock::FakeClock::install(&ctx);
+        let duration = f64::from(ctx.style().animation_time) * 3.0;
+        // The opacity of a white square inside the section once the clock moved forward
+        let opacity_after = |seconds: f64| {
+            clock.advance(seconds);
+            let output = ctx.run(egui::RawInput::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    reveal_on_scroll(ui, egui::Id::new("section"), |ui| {
+                        let (rect, _) = ui.allocate_exact_size(Vec2::splat(20.0), Sense::hover());
+                        ui.painter().rect_filled(rect, 0.0, Color32::WHITE);
+                    });
+                });
+            });
+            output.shapes.iter().find_map(|clipped| match &clipped.shape {
+                egui::Shape::Rect(square) if square.rect.width() == 20.0 => Some(f32::from(square.fill.a()) / 255.0),
+                _ => None,
+            }).unwrap_or(0.0)
+        };
+        assert_eq!(opacity_after(0.0), 0.0, "hidden until first laid out in view");
+        assert_eq!(opacity_after(0.0), 0.0, "starts fading in once seen");
+        let halfway = opacity_after(duration / 2.0);
+        assert!((halfway - emath::easing::cubic_out(0.5)).abs() < 1.0 / 255.0, "eased halfway in, not {halfway}");
+        assert_eq!(opacity_after(duration / 2.0), 1.0);
+    }
+
+    #[test]
+    fn status_dot_pulses_with_the_clock() {
+        let ctx = egui::Context::default();
+        let clock = crate::clock::FakeClock::install(&ctx);
+        // The halo around the dot grows to twice its radius as the pulse fades in
+        let pulse_after = |seconds: f64| {
+            clock.advance(seconds);
+            let output = ctx.run(egui::RawInput::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| status_pill(ui, "Building", Color32::RED, 20.0));
+            });
+            let radii: Vec<f32> = output.shapes.iter().filter_map(|clipped| match &clipped.shape {
+                egui::Shape::Circle(circle) => Some(circle.radius),
+                _ => None,
+            }).collect();
+            let [halo, dot] = radii[..] else { panic!("expected a halo and a dot, got {radii:?}") };
+            (halo / dot - 1.0, output.viewport_output[&egui::ViewportId::ROOT].repaint_delay.as_secs_f64())
+        };
+        // egui repaints right away after the first frame, only the second one can sleep
+        let (pulse, _) = pulse_after(0.0);
+        assert_eq!(pulse, 1.0, "the first frame snaps into the first half period");
+        let (pulse, repaint_delay) = pulse_after(0.25);
+        assert_eq!(pulse, 1.0);
+        // egui wakes up one predicted frame early, so allow for that
+        assert!((repaint_delay - 0.55).abs() < 0.05, "sleeps until the next half period, not {repaint_delay}");
+        // The second half period starts fading out
+        let (pulse, _) = pulse_after(0.75);
+        assert_eq!(pulse, 1.0);
+        let (pulse, repaint_delay) = pulse_after(0.2);
+        assert!((pulse - 0.75).abs() < 1e-4, "a quarter of the way out, not {pulse}");
+        assert_eq!(repaint_delay, 0.0, "repaints every frame while moving");
+        let (pulse, _) = pulse_after(0.6);
+        assert!(pulse.abs() < 1e-4, "faded out, not {pulse}");
+        let (pulse, _) = pulse_after(0.4);
+        assert!((pulse - 0.5).abs() < 1e-4, "halfway back in, not {pulse}");
+    }
+
+    #[test]
+    fn clearing_filters_shows_every_project_again() {
+        let project = &project("maps", &["rust"]);
+        let mut gallery = ProjectGallery { query: "svelte".to_owned(), page: 2, ..Default::default() };
+        gallery.active_tags.insert("rust".to_owned());
+        assert!(!gallery.matches(project));
+
+        gallery.clear_filters();
+        assert!(gallery.matches(project));
+        assert_eq!((gallery.query.as_str(), gallery.active_tags.len(), gallery.page), ("", 0, 0));
+    }
+
+    #[test]
+    fn extra_tags_fold_into_one_chip() {
+        let tags = &project("maps", &["rust", "OSM", "concurrency", "wasm", "tiles"]).tags;
+        // Without selectable labels only the "+N" chip senses clicks, the tag chips are plain cards
+        let overflow_chips = |max_visible: usize| {
+            let ctx = egui::Context::default();
+            ctx.style_mut(|style| style.interaction.selectable_labels = false);
+            let _: egui::FullOutput = ctx.run(egui::RawInput::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| ui.horizontal(|ui| tag_chips(ui, tags, "", max_visible)));
+            });
+            last_pass_widgets(&ctx).iter().filter(|widget| widget.sense.senses_click()).count()
+        };
+        assert_eq!(overflow_chips(3), 1);
+        assert_eq!(overflow_chips(4), 0, "folding a single tag saves no room");
+        assert_eq!(overflow_chips(usize::MAX), 0);
+    }
+
+    #[test]
+    fn auto_hiding_scrollbars_show_while_scrolling() {
+        let ctx = egui::Context::default();
+        let clock = crate::clock::FakeClock::install(&ctx);
+        let scroll_id = egui::Id::new("page");
+        let base = egui::style::ScrollStyle::floating();
+        let handle_opacity_after = |seconds: f64, offset: f32| {
+            clock.advance(seconds);
+            let mut state = egui::scroll_area::State::default();
+            state.offset.y = offset;
+            state.store(&ctx, scroll_id);
+            scrollbar_style(&ctx, scroll_id, base, ScrollbarVisibility::AutoHide).dormant_handle_opacity
+        };
+        assert_eq!(handle_opacity_after(0.0, 120.0), 0.0, "a restored offset isn't scrolling");
+        handle_opacity_after(0.1, 140.0);
+        assert_eq!(handle_opacity_after(1.0, 160.0), base.active_handle_opacity);
+        assert_eq!(handle_opacity_after(0.5, 160.0), base.active_handle_opacity, "lingers after scrolling stops");
+        assert_eq!(handle_opacity_after(0.4, 160.0), base.active_handle_opacity, "starts fading out");
+        assert_eq!(handle_opacity_after(1.0, 160.0), 0.0, "hidden once faded out");
+
+        let always = scrollbar_style(&ctx, scroll_id, base, ScrollbarVisibility::Always);
+        assert_eq!(always.dormant_handle_opacity, base.active_handle_opacity);
+        assert_eq!(always.dormant_background_opacity, base.active_background_opacity);
+    }
+}
@@ -1,6 +1,11 @@
 #![warn(clippy::all, rust_2018_idioms)]
 
 mod app;
-pub use app::TemplateApp;
+pub use app::{Breakpoints, ScreenSize, TemplateApp};
 mod elements;
-mod data;
\ No newline at end of file
+mod data;
+mod analytics;
+mod route;
+pub use route::Route;
+pub mod color;
+pub mod clock;
\ No newline at end of file
@@ -0,0 +1,115 @@
+/// A page of the portfolio as addressed by the location hash, e.g. `#/projects/rust-maps`.
+///
+/// Everything is one scrolling page, so most routes name the section to scroll to, while
+/// [`Route::Project`] opens that project's detail overlay.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Route {
+    #[default]
+    Home,
+    Projects,
+    Project(String), // The project's slug
+    Skills,
+    Contact,
+}
+
+impl Route {
+    const PROJECTS: &str = "projects";
+    const SKILLS: &str = "skills";
+    const CONTACT: &str = "contact";
+
+    /// Parses a location hash, with or without its leading `#`.
+    /// Unknown hashes fall back to [`Route::Home`], so stale or mistyped links still land on the page.
+    pub fn from_hash(hash: &str) -> Self {
+        let path = hash.strip_prefix('#').unwrap_or(hash);
+        let path = path.strip_prefix('/').unwrap_or(path).trim_end_matches('/');
+        match path.split_once('/') {
+            Some((Self::PROJECTS, slug)) if !slug.is_empty() && !slug.contains('/') => Self::Project(percent_decode(slug)),
+            Some(_) => Self::Home,
+            None => match path {
+                Self::PROJECTS => Self::Projects,
+                Self::SKILLS => Self::Skills,
+                Self::CONTACT => Self::Contact,
+                _ => Self::Home,
+            },
+        }
+    }
+
+    /// The location hash for this route, including the leading `#`.
+    pub fn to_hash(&self) -> String {
+        match self {
+            Self::Home => "#".to_owned(),
+            Self::Projects => format!("#/{}", Self::PROJECTS),
+            Self::Project(slug) => format!("#/{}/{}", Self::PROJECTS, slug),
+            Self::Skills => format!("#/{}", Self::SKILLS),
+            Self::Contact => format!("#/{}", Self::CONTACT),
+        }
+    }
+}
+
+/// Decodes the `%XX` escapes browsers put into `location.hash`, e.g. for a slug typed with spaces or accents.
+/// Malformed escapes are kept as they are, and bytes that don't form valid UTF-8 are replaced.
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| bytes.get(i + 1..i + 3))
+            .flatten()
+            .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        if let Some(byte) = escaped {
+            decoded.push(byte);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn routes_round_trip_through_the_hash() {
+        let routes = [
+            Route::Home,
+            Route::Projects,
+            Route::Project("rust-maps".to_owned()),
+            Route::Skills,
+            Route::Contact,
+        ];
+        for route in routes {
+            assert_eq!(Route::from_hash(&route.to_hash()), route, "{}", route.to_hash());
+        }
+    }
+
+    #[test]
+    fn hashes_parse_leniently_and_fall_back_to_home() {
+        assert_eq!(Route::from_hash("#/projects/rust-maps"), Route::Project("rust-maps".to_owned()));
+        assert_eq!(Route::from_hash("projects/rust-maps/"), Route::Project("rust-maps".to_owned()));
+        assert_eq!(Route::from_hash("#/contact/"), Route::Contact);
+        assert_eq!(Route::from_hash("#projects"), Route::Projects);
+        assert_eq!(Route::from_hash("#/projects/"), Route::Projects);
+        assert_eq!(Route::from_hash(""), Route::Home);
+        assert_eq!(Route::from_hash("#/"), Route::Home);
+        assert_eq!(Route::from_hash("#/blog"), Route::Home);
+        assert_eq!(Route::from_hash("#/projects/rust-maps/gallery"), Route::Home);
+        assert_eq!(Route::from_hash("#/skills/rust"), Route::Home);
+    }
+
+    #[test]
+    fn project_slugs_are_percent_decoded() {
+        assert_eq!(Route::from_hash("#/projects/rust%20maps"), Route::Project("rust maps".to_owned()));
+        assert_eq!(Route::from_hash("#/projects/caf%C3%A9"), Route::Project("café".to_owned()));
+        assert_eq!(Route::from_hash("#/projects/100%25-rust"), Route::Project("100%-rust".to_owned()));
+        // Malformed escapes are left alone rather than dropping the link
+        assert_eq!(Route::from_hash("#/projects/50%-off"), Route::Project("50%-off".to_owned()));
+        assert_eq!(Route::from_hash("#/projects/maps%+f"), Route::Project("maps%+f".to_owned()));
+        assert_eq!(Route::from_hash("#/projects/maps%2"), Route::Project("maps%2".to_owned()));
+    }
+}